@@ -1,13 +1,14 @@
 extern crate splines;
 
+use heapless::Vec;
 use splines::{Interpolation, Key, Spline};
 
 fn main() {
-  let keys = vec![
+  let keys = [
     Key::new(0., 0., Interpolation::default()),
     Key::new(5., 1., Interpolation::default()),
   ];
-  let spline = Spline::from_vec(keys);
+  let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
 
   println!("value at 0: {:?}", spline.clamped_sample(0.));
   println!("value at 3: {:?}", spline.clamped_sample(3.));
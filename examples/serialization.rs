@@ -26,6 +26,6 @@ fn main() {
     ]
   };
 
-  let spline = from_value::<Spline<f32, f32>>(value);
+  let spline = from_value::<Spline<f32, f32, 3>>(value);
   println!("{:?}", spline);
 }
@@ -0,0 +1,136 @@
+//! Array implementors.
+//!
+//! Arrays of scalars are interpolated component-wise with the scalar implementations, which allows to interpolate
+//! vectors of any dimension without depending on a math crate.
+//!
+//! Arrays don’t support arithmetic operations, so the operations on splines requiring [`Linear`] values – e.g.
+//! derivatives or arc length – are not available to them.
+//!
+//! [`Linear`]: crate::interpolate::Linear
+
+use crate::interpolate::{Interpolate, MinMax};
+use num_traits::Float;
+
+// Apply a scalar function to every component of several arrays.
+fn componentwise<T, const N: usize, const M: usize>(
+  values: [[T; N]; M],
+  f: impl Fn([T; M]) -> T,
+) -> [T; N]
+where
+  T: Copy,
+{
+  core::array::from_fn(|i| f(values.map(|value| value[i])))
+}
+
+macro_rules! impl_Interpolate_array {
+  ($t:ty) => {
+    impl<const N: usize> Interpolate<$t> for [$t; N] {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a, b], |[a, b]| <$t>::lerp(t, a, b))
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a, b], |[a, b]| <$t>::cosine(t, a, b))
+      }
+
+      fn smoothstep(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a, b], |[a, b]| <$t>::smoothstep(t, a, b))
+      }
+
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a, b], |[a, b]| <$t>::smootherstep(t, a, b))
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        componentwise([x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::cubic_hermite(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+      }
+
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        componentwise([a.1, b.1, m0, m1], |[av, bv, m0, m1]| {
+          <$t>::hermite(t, (a.0, av), (b.0, bv), m0, m1)
+        })
+      }
+
+      fn monotone_cubic(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        componentwise([x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::monotone_cubic(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+      }
+
+      fn akima(t: $t, keys: [($t, Self); 6]) -> Self {
+        componentwise(keys.map(|(_, v)| v), |values| {
+          <$t>::akima(t, core::array::from_fn(|i| (keys[i].0, values[i])))
+        })
+      }
+
+      fn exponential(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a, b], |[a, b]| <$t>::exponential(t, a, b))
+      }
+
+      fn quintic_hermite(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Self {
+        componentwise([x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::quintic_hermite(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        componentwise([x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::kochanek_bartels(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv), tcb_a, tcb_b)
+        })
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        componentwise([a, u, b], |[a, u, b]| <$t>::quadratic_bezier(t, a, u, b))
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        componentwise([a, u, v, b], |[a, u, v, b]| {
+          <$t>::cubic_bezier(t, a, u, v, b)
+        })
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        componentwise([a, u, v, b], |[a, u, v, b]| {
+          <$t>::cubic_bezier_mirrored(t, a, u, v, b)
+        })
+      }
+    }
+
+    impl<const N: usize> MinMax for [$t; N] {
+      fn min(self, other: Self) -> Self {
+        componentwise([self, other], |[a, b]| Float::min(a, b))
+      }
+
+      fn max(self, other: Self) -> Self {
+        componentwise([self, other], |[a, b]| Float::max(a, b))
+      }
+    }
+  };
+}
+
+impl_Interpolate_array!(f32);
+impl_Interpolate_array!(f64);
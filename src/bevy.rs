@@ -0,0 +1,30 @@
+//! [bevy] reflection.
+//!
+//! [`Key`] and [`Interpolation`] derive [`Reflect`], so that their fields can be inspected and edited. The keys of a
+//! [`Spline`] must stay sorted, so splines are reflected as opaque values instead: they can be cloned, compared and
+//! – with the `"serde"` feature – serialized through reflection, e.g. in scenes, but not edited key by key.
+//!
+//! [bevy]: https://crates.io/crates/bevy
+//! [`Key`]: crate::key::Key
+//! [`Interpolation`]: crate::interpolation::Interpolation
+//! [`Reflect`]: bevy_reflect::Reflect
+
+use crate::spline::Spline;
+use bevy_reflect::{impl_reflect_value, TypePath};
+#[cfg(any(feature = "serialization", feature = "serde"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use core::fmt::Debug;
+
+#[cfg(not(any(feature = "serialization", feature = "serde")))]
+impl_reflect_value!((in splines::spline) Spline<
+  T: Clone + Debug + PartialEq + Send + Sync + TypePath,
+  V: Clone + Debug + PartialEq + Send + Sync + TypePath,
+  const SIZE: usize
+>(Debug, PartialEq));
+
+#[cfg(any(feature = "serialization", feature = "serde"))]
+impl_reflect_value!((in splines::spline) Spline<
+  T: Clone + Debug + PartialOrd + Send + Sync + TypePath + serde::Serialize + for<'de> serde::Deserialize<'de>,
+  V: Clone + Debug + PartialEq + Send + Sync + TypePath + serde::Serialize + for<'de> serde::Deserialize<'de>,
+  const SIZE: usize
+>(Debug, PartialEq, Serialize, Deserialize));
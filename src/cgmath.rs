@@ -1,15 +1,88 @@
 use crate::impl_Interpolate;
+use crate::interpolate::{impl_circular_arc, MinMax, Norm};
+use crate::quaternion::impl_Interpolate_quaternion;
 
-use cgmath::{Quaternion, Vector1, Vector2, Vector3, Vector4};
+use cgmath::{InnerSpace, Quaternion, Vector1, Vector2, Vector3, Vector4};
 
 impl_Interpolate!(f32, Vector1<f32>, std::f32::consts::PI);
-impl_Interpolate!(f32, Vector2<f32>, std::f32::consts::PI);
-impl_Interpolate!(f32, Vector3<f32>, std::f32::consts::PI);
-impl_Interpolate!(f32, Vector4<f32>, std::f32::consts::PI);
-impl_Interpolate!(f32, Quaternion<f32>, std::f32::consts::PI);
+impl_Interpolate!(f32, Vector2<f32>, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vector3<f32>, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vector4<f32>, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
 
 impl_Interpolate!(f64, Vector1<f64>, std::f64::consts::PI);
-impl_Interpolate!(f64, Vector2<f64>, std::f64::consts::PI);
-impl_Interpolate!(f64, Vector3<f64>, std::f64::consts::PI);
-impl_Interpolate!(f64, Vector4<f64>, std::f64::consts::PI);
-impl_Interpolate!(f64, Quaternion<f64>, std::f64::consts::PI);
+impl_Interpolate!(f64, Vector2<f64>, std::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, Vector3<f64>, std::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, Vector4<f64>, std::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+
+impl_Interpolate_quaternion!(
+  f32,
+  Quaternion<f32>,
+  Vector4<f32>,
+  <Quaternion<f32> as Into<[f32; 4]>>::into,
+  Quaternion::from
+);
+impl_Interpolate_quaternion!(
+  f64,
+  Quaternion<f64>,
+  Vector4<f64>,
+  <Quaternion<f64> as Into<[f64; 4]>>::into,
+  Quaternion::from
+);
+
+macro_rules! impl_Norm {
+  ($t:ty, $v:ty) => {
+    impl Norm<$t> for $v {
+      fn norm(self) -> $t {
+        self.magnitude()
+      }
+    }
+  };
+}
+
+impl_Norm!(f32, Vector1<f32>);
+impl_Norm!(f32, Vector2<f32>);
+impl_Norm!(f32, Vector3<f32>);
+impl_Norm!(f32, Vector4<f32>);
+impl_Norm!(f32, Quaternion<f32>);
+
+impl_Norm!(f64, Vector1<f64>);
+impl_Norm!(f64, Vector2<f64>);
+impl_Norm!(f64, Vector3<f64>);
+impl_Norm!(f64, Vector4<f64>);
+impl_Norm!(f64, Quaternion<f64>);
+
+macro_rules! impl_MinMax {
+  ($v:ty) => {
+    impl MinMax for $v {
+      fn min(self, other: Self) -> Self {
+        self.zip(other, |a, b| a.min(b))
+      }
+
+      fn max(self, other: Self) -> Self {
+        self.zip(other, |a, b| a.max(b))
+      }
+    }
+  };
+}
+
+impl_MinMax!(Vector1<f32>);
+impl_MinMax!(Vector2<f32>);
+impl_MinMax!(Vector3<f32>);
+impl_MinMax!(Vector4<f32>);
+
+impl_MinMax!(Vector1<f64>);
+impl_MinMax!(Vector2<f64>);
+impl_MinMax!(Vector3<f64>);
+impl_MinMax!(Vector4<f64>);
@@ -0,0 +1,112 @@
+//! Compact serialized representation of splines.
+//!
+//! A [`Spline`] is serialized as a sequence of keys, repeating the field names of [`Key`] for every key in
+//! self-describing formats such as JSON. [`CompactSpline`] wraps a spline to serialize its keys as three parallel
+//! sequences instead – sampling parameters, values and interpolation modes:
+//!
+//! ```json
+//! {
+//!   "t": [0.0, 1.0, 5.0],
+//!   "values": [0.0, 1.0, 10.0],
+//!   "interpolations": ["linear", { "step": 0.5 }, "cosine"]
+//! }
+//! ```
+//!
+//! [`Spline`]: crate::spline::Spline
+//! [`Key`]: crate::key::Key
+
+use crate::interpolation::Interpolation;
+use crate::key::Key;
+use crate::spline::Spline;
+use heapless::Vec;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Spline serialized as parallel sequences of sampling parameters, values and interpolation modes.
+///
+/// Convert from and to [`Spline`] with [`From`]. As with [`Spline`], deserialized keys don’t have to be sorted.
+#[derive(Clone, Debug, Default)]
+pub struct CompactSpline<T, V, const SIZE: usize>(pub Spline<T, V, SIZE>);
+
+impl<T, V, const SIZE: usize> From<Spline<T, V, SIZE>> for CompactSpline<T, V, SIZE> {
+  fn from(spline: Spline<T, V, SIZE>) -> Self {
+    CompactSpline(spline)
+  }
+}
+
+impl<T, V, const SIZE: usize> From<CompactSpline<T, V, SIZE>> for Spline<T, V, SIZE> {
+  fn from(spline: CompactSpline<T, V, SIZE>) -> Self {
+    spline.0
+  }
+}
+
+// Borrowed columns of a spline, for serialization.
+#[derive(Serialize)]
+#[serde(rename = "CompactSpline")]
+struct ColumnsRef<'a, T, V, const SIZE: usize> {
+  t: Vec<&'a T, SIZE>,
+  values: Vec<&'a V, SIZE>,
+  interpolations: Vec<&'a Interpolation<T, V>, SIZE>,
+}
+
+// Owned columns of a spline, for deserialization.
+#[derive(Deserialize)]
+#[serde(rename = "CompactSpline")]
+struct Columns<T, V, const SIZE: usize> {
+  t: Vec<T, SIZE>,
+  values: Vec<V, SIZE>,
+  interpolations: Vec<Interpolation<T, V>, SIZE>,
+}
+
+impl<T, V, const SIZE: usize> Serialize for CompactSpline<T, V, SIZE>
+where
+  T: Serialize,
+  V: Serialize,
+{
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let keys = self.0.keys();
+    let columns = ColumnsRef::<T, V, SIZE> {
+      t: keys.iter().map(|key| &key.t).collect(),
+      values: keys.iter().map(|key| &key.value).collect(),
+      interpolations: keys.iter().map(|key| &key.interpolation).collect(),
+    };
+
+    columns.serialize(serializer)
+  }
+}
+
+impl<'de, T, V, const SIZE: usize> Deserialize<'de> for CompactSpline<T, V, SIZE>
+where
+  T: PartialOrd + Deserialize<'de>,
+  V: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let columns = Columns::<T, V, SIZE>::deserialize(deserializer)?;
+    let len = columns.t.len();
+
+    if columns.values.len() != len || columns.interpolations.len() != len {
+      return Err(D::Error::custom(format_args!(
+        "mismatched lengths: {} sampling parameters, {} values and {} interpolation modes",
+        len,
+        columns.values.len(),
+        columns.interpolations.len()
+      )));
+    }
+
+    let keys = columns
+      .t
+      .into_iter()
+      .zip(columns.values)
+      .zip(columns.interpolations)
+      .map(|((t, value), interpolation)| Key::new(t, value, interpolation))
+      .collect();
+
+    Ok(CompactSpline(Spline::from_keys_unsorted(keys)))
+  }
+}
@@ -0,0 +1,164 @@
+//! [`Duration`] implementors.
+//!
+//! Durations can be used as the sampling parameter of splines carrying [`f32`] or [`f64`] values, so that animations
+//! keyed by time can be sampled by time directly. The normalized sampling parameter of a segment is itself a
+//! [`Duration`], one second standing for the end of the segment: interpolation modes taking parameters – e.g. the
+//! threshold of [`Interpolation::Step`] – expect them expressed that way.
+//!
+//! Durations cannot be negative: normalizing a duration before the start of a segment yields [`Duration::ZERO`], as
+//! does normalizing with an empty segment.
+//!
+//! [`Interpolation::Step`]: crate::interpolation::Interpolation::Step
+
+use crate::interpolate::{Interpolate, Interpolator};
+use core::time::Duration;
+
+impl Interpolator for Duration {
+  fn normalize(self, start: Self, end: Self) -> Self {
+    let len = end.saturating_sub(start);
+
+    if len.is_zero() {
+      Duration::ZERO
+    } else {
+      Duration::from_secs_f64(self.saturating_sub(start).as_secs_f64() / len.as_secs_f64())
+    }
+  }
+}
+
+macro_rules! impl_Interpolate_duration {
+  ($v:ty, $as_secs:ident) => {
+    impl Interpolate<Duration> for $v {
+      fn step(t: Duration, threshold: Duration, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: Duration, a: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::lerp(t.$as_secs(), a, b)
+      }
+
+      fn cosine(t: Duration, a: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::cosine(t.$as_secs(), a, b)
+      }
+
+      fn smoothstep(t: Duration, a: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::smoothstep(t.$as_secs(), a, b)
+      }
+
+      fn smootherstep(t: Duration, a: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::smootherstep(t.$as_secs(), a, b)
+      }
+
+      fn cubic_hermite(
+        t: Duration,
+        x: (Duration, Self),
+        a: (Duration, Self),
+        b: (Duration, Self),
+        y: (Duration, Self),
+      ) -> Self {
+        let k = |(t, v): (Duration, Self)| (t.$as_secs(), v);
+        <$v as Interpolate<$v>>::cubic_hermite(t.$as_secs(), k(x), k(a), k(b), k(y))
+      }
+
+      fn hermite(
+        t: Duration,
+        a: (Duration, Self),
+        b: (Duration, Self),
+        m0: Self,
+        m1: Self,
+      ) -> Self {
+        let k = |(t, v): (Duration, Self)| (t.$as_secs(), v);
+        <$v as Interpolate<$v>>::hermite(t.$as_secs(), k(a), k(b), m0, m1)
+      }
+
+      fn monotone_cubic(
+        t: Duration,
+        x: (Duration, Self),
+        a: (Duration, Self),
+        b: (Duration, Self),
+        y: (Duration, Self),
+      ) -> Self {
+        let k = |(t, v): (Duration, Self)| (t.$as_secs(), v);
+        <$v as Interpolate<$v>>::monotone_cubic(t.$as_secs(), k(x), k(a), k(b), k(y))
+      }
+
+      fn monotone_tangents(
+        x: (Duration, Self),
+        a: (Duration, Self),
+        b: (Duration, Self),
+        y: (Duration, Self),
+      ) -> Option<(Self, Self)> {
+        let k = |(t, v): (Duration, Self)| (t.$as_secs(), v);
+        <$v as Interpolate<$v>>::monotone_tangents(k(x), k(a), k(b), k(y))
+      }
+
+      fn akima(t: Duration, keys: [(Duration, Self); 6]) -> Self {
+        <$v as Interpolate<$v>>::akima(t.$as_secs(), keys.map(|(t, v)| (t.$as_secs(), v)))
+      }
+
+      fn akima_tangents(keys: [(Duration, Self); 6]) -> Option<(Self, Self)> {
+        <$v as Interpolate<$v>>::akima_tangents(keys.map(|(t, v)| (t.$as_secs(), v)))
+      }
+
+      fn exponential(t: Duration, a: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::exponential(t.$as_secs(), a, b)
+      }
+
+      fn exponential_derivative(t: Duration, a: Self, b: Self) -> Option<Self> {
+        <$v as Interpolate<$v>>::exponential_derivative(t.$as_secs(), a, b)
+      }
+
+      fn quintic_hermite(
+        t: Duration,
+        x: (Duration, Self),
+        a: (Duration, Self),
+        b: (Duration, Self),
+        y: (Duration, Self),
+      ) -> Self {
+        let k = |(t, v): (Duration, Self)| (t.$as_secs(), v);
+        <$v as Interpolate<$v>>::quintic_hermite(t.$as_secs(), k(x), k(a), k(b), k(y))
+      }
+
+      fn kochanek_bartels(
+        t: Duration,
+        x: (Duration, Self),
+        a: (Duration, Self),
+        b: (Duration, Self),
+        y: (Duration, Self),
+        tcb_a: (Duration, Duration, Duration),
+        tcb_b: (Duration, Duration, Duration),
+      ) -> Self {
+        let k = |(t, v): (Duration, Self)| (t.$as_secs(), v);
+        let tcb =
+          |(t, c, b): (Duration, Duration, Duration)| (t.$as_secs(), c.$as_secs(), b.$as_secs());
+        <$v as Interpolate<$v>>::kochanek_bartels(
+          t.$as_secs(),
+          k(x),
+          k(a),
+          k(b),
+          k(y),
+          tcb(tcb_a),
+          tcb(tcb_b),
+        )
+      }
+
+      fn quadratic_bezier(t: Duration, a: Self, u: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::quadratic_bezier(t.$as_secs(), a, u, b)
+      }
+
+      fn cubic_bezier(t: Duration, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::cubic_bezier(t.$as_secs(), a, u, v, b)
+      }
+
+      fn cubic_bezier_mirrored(t: Duration, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$v as Interpolate<$v>>::cubic_bezier_mirrored(t.$as_secs(), a, u, v, b)
+      }
+    }
+  };
+}
+
+impl_Interpolate_duration!(f32, as_secs_f32);
+impl_Interpolate_duration!(f64, as_secs_f64);
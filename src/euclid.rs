@@ -0,0 +1,183 @@
+//! [euclid] implementors.
+//!
+//! The unit of the types is kept: interpolating values of a given unit yields a value of the same unit. Points cannot
+//! be added together, so they are interpolated as vectors from the origin.
+//!
+//! [euclid]: https://crates.io/crates/euclid
+
+use crate::impl_Interpolate;
+use crate::interpolate::{circular_arc, impl_circular_arc, Interpolate, MinMax, Norm};
+use crate::key::Key;
+use crate::spline::circle_key_tangent;
+use euclid::{Point2D, Point3D, Vector2D, Vector3D};
+use num_traits::Float;
+
+impl_Interpolate!(<U> f32, Vector2D<f32, U>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(<U> f32, Vector3D<f32, U>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+
+impl_Interpolate!(<U> f64, Vector2D<f64, U>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(<U> f64, Vector3D<f64, U>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+
+macro_rules! impl_Interpolate_point {
+  ($t:ty, $p:ident, $v:ident) => {
+    impl<U> Interpolate<$t> for $p<$t, U> {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::lerp(t, a.to_vector(), b.to_vector()).to_point()
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::cosine(t, a.to_vector(), b.to_vector()).to_point()
+      }
+
+      fn smoothstep(t: $t, a: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::smoothstep(t, a.to_vector(), b.to_vector()).to_point()
+      }
+
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::smootherstep(t, a.to_vector(), b.to_vector()).to_point()
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        let v = |(t, p): ($t, Self)| (t, p.to_vector());
+        <$v<$t, U> as Interpolate<$t>>::cubic_hermite(t, v(x), v(a), v(b), v(y)).to_point()
+      }
+
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        let v = |(t, p): ($t, Self)| (t, p.to_vector());
+        <$v<$t, U> as Interpolate<$t>>::hermite(t, v(a), v(b), m0.to_vector(), m1.to_vector())
+          .to_point()
+      }
+
+      fn quintic_hermite(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Self {
+        let v = |(t, p): ($t, Self)| (t, p.to_vector());
+        <$v<$t, U> as Interpolate<$t>>::quintic_hermite(t, v(x), v(a), v(b), v(y)).to_point()
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        let v = |(t, p): ($t, Self)| (t, p.to_vector());
+        <$v<$t, U> as Interpolate<$t>>::kochanek_bartels(t, v(x), v(a), v(b), v(y), tcb_a, tcb_b)
+          .to_point()
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::quadratic_bezier(
+          t,
+          a.to_vector(),
+          u.to_vector(),
+          b.to_vector(),
+        )
+        .to_point()
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::cubic_bezier(
+          t,
+          a.to_vector(),
+          u.to_vector(),
+          v.to_vector(),
+          b.to_vector(),
+        )
+        .to_point()
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$v<$t, U> as Interpolate<$t>>::cubic_bezier_mirrored(
+          t,
+          a.to_vector(),
+          u.to_vector(),
+          v.to_vector(),
+          b.to_vector(),
+        )
+        .to_point()
+      }
+
+      // points cannot be differentiated, so their arcs follow the circle passing through the previous key
+      fn circular_arc<M>(t: $t, keys: &[Key<$t, Self, M>], i: usize) -> Self {
+        let tangent = circle_key_tangent(keys, i, |p| p.to_vector());
+        circular_arc(
+          t,
+          keys[i].value.to_vector(),
+          keys[i + 1].value.to_vector(),
+          tangent,
+        )
+        .to_point()
+      }
+    }
+  };
+}
+
+impl_Interpolate_point!(f32, Point2D, Vector2D);
+impl_Interpolate_point!(f32, Point3D, Vector3D);
+
+impl_Interpolate_point!(f64, Point2D, Vector2D);
+impl_Interpolate_point!(f64, Point3D, Vector3D);
+
+macro_rules! impl_Norm {
+  ($t:ty, $v:ident) => {
+    impl<U> Norm<$t> for $v<$t, U> {
+      fn norm(self) -> $t {
+        Float::sqrt(self.square_length())
+      }
+    }
+  };
+}
+
+impl_Norm!(f32, Vector2D);
+impl_Norm!(f32, Vector3D);
+
+impl_Norm!(f64, Vector2D);
+impl_Norm!(f64, Vector3D);
+
+macro_rules! impl_MinMax {
+  ($t:ty, $v:ident) => {
+    impl<U> MinMax for $v<$t, U> {
+      fn min(self, other: Self) -> Self {
+        $v::min(self, other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        $v::max(self, other)
+      }
+    }
+  };
+}
+
+impl_MinMax!(f32, Vector2D);
+impl_MinMax!(f32, Vector3D);
+impl_MinMax!(f32, Point2D);
+impl_MinMax!(f32, Point3D);
+
+impl_MinMax!(f64, Vector2D);
+impl_MinMax!(f64, Vector3D);
+impl_MinMax!(f64, Point2D);
+impl_MinMax!(f64, Point3D);
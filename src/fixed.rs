@@ -0,0 +1,194 @@
+//! [fixed] implementors.
+//!
+//! Fixed-point numbers are both the sampling parameter and the interpolated value, so that sampling is entirely
+//! computed with fixed-point arithmetic and yields the same results on every target. Cosine interpolation uses a
+//! polynomial approximation of the cosine, accurate to the resolution of the supported types.
+//!
+//! Interpolation modes requiring a floating-point type, such as [`Interpolation::MonotoneCubic`],
+//! [`Interpolation::Akima`], [`Interpolation::Exponential`] and [`Interpolation::CircularArc`], fall back to their
+//! default implementations.
+//!
+//! Intermediate results of cubic and quintic modes – e.g. tangents – must be representable by the type: they
+//! overflow, panicking in debug builds and wrapping otherwise, if values or key gaps get close to the range of the
+//! type.
+//!
+//! [fixed]: https://crates.io/crates/fixed
+//! [`Interpolation::MonotoneCubic`]: crate::interpolation::Interpolation::MonotoneCubic
+//! [`Interpolation::Akima`]: crate::interpolation::Interpolation::Akima
+//! [`Interpolation::Exponential`]: crate::interpolation::Interpolation::Exponential
+//! [`Interpolation::CircularArc`]: crate::interpolation::Interpolation::CircularArc
+
+use crate::interpolate::{Interpolate, Interpolator, MinMax, Norm};
+use fixed::types::{I16F16, I32F32, I48F16, I8F24};
+
+macro_rules! impl_Interpolate_fixed {
+  ($t:ty) => {
+    impl Interpolator for $t {
+      fn normalize(self, start: Self, end: Self) -> Self {
+        (self - start) / (end - start)
+      }
+    }
+
+    impl Interpolate<$t> for $t {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        a + (b - a) * t
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        // (1 - cos(πt)) / 2 = (1 + sin(π(t - 1/2))) / 2, with the Taylor series of the sine up to x⁹
+        let x = (t - <$t>::ONE / 2) * <$t>::PI;
+        let x2 = x * x;
+        let one = <$t>::ONE;
+        let sin = x * (one - x2 / 6 * (one - x2 / 20 * (one - x2 / 42 * (one - x2 / 72))));
+        let cos_nt = (<$t>::ONE + sin) / 2;
+
+        <Self as Interpolate<$t>>::lerp(cos_nt, a, b)
+      }
+
+      fn smoothstep(t: $t, a: Self, b: Self) -> Self {
+        let eased_t = t * t * (<$t>::from_num(3) - t * 2);
+        <Self as Interpolate<$t>>::lerp(eased_t, a, b)
+      }
+
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        let eased_t = t * t * t * (t * (t * 6 - <$t>::from_num(15)) + <$t>::from_num(10));
+        <Self as Interpolate<$t>>::lerp(eased_t, a, b)
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        // tangents
+        let m0 = (b.1 - x.1) / (b.0 - x.0);
+        let m1 = (y.1 - a.1) / (y.0 - a.0);
+
+        <Self as Interpolate<$t>>::hermite(t, a, b, m0, m1)
+      }
+
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two_t3 = t3 * 2;
+        let three_t2 = t2 * 3;
+
+        // tangents, with respect to the normalized sampling parameter
+        let m0 = m0 * (b.0 - a.0);
+        let m1 = m1 * (b.0 - a.0);
+
+        a.1 * (two_t3 - three_t2 + <$t>::ONE)
+          + m0 * (t3 - t2 * 2 + t)
+          + b.1 * (three_t2 - two_t3)
+          + m1 * (t3 - t2)
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        let one = <$t>::ONE;
+
+        // tangents
+        let (tension, continuity, bias) = tcb_a;
+        let m0 = ((a.1 - x.1) * ((one + bias) * (one + continuity))
+          + (b.1 - a.1) * ((one - bias) * (one - continuity)))
+          * ((one - tension) / (b.0 - x.0));
+        let (tension, continuity, bias) = tcb_b;
+        let m1 = ((b.1 - a.1) * ((one + bias) * (one - continuity))
+          + (y.1 - b.1) * ((one - bias) * (one + continuity)))
+          * ((one - tension) / (y.0 - a.0));
+
+        <Self as Interpolate<$t>>::hermite(t, a, b, m0, m1)
+      }
+
+      fn quintic_hermite(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Self {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+        let t5 = t4 * t;
+
+        // velocities
+        let h = b.0 - a.0;
+        let m0 = (b.1 - x.1) / (b.0 - x.0) * h;
+        let m1 = (y.1 - a.1) / (y.0 - a.0) * h;
+
+        // accelerations
+        let slope = (b.1 - a.1) / h;
+        let acc0 = if x.0 < a.0 {
+          (slope - (a.1 - x.1) / (a.0 - x.0)) * 2 / (b.0 - x.0) * h * h
+        } else {
+          <$t>::ZERO
+        };
+        let acc1 = if y.0 > b.0 {
+          ((y.1 - b.1) / (y.0 - b.0) - slope) * 2 / (y.0 - a.0) * h * h
+        } else {
+          <$t>::ZERO
+        };
+
+        a.1 * (<$t>::ONE - t3 * 10 + t4 * 15 - t5 * 6)
+          + m0 * (t - t3 * 6 + t4 * 8 - t5 * 3)
+          + acc0 * ((t2 - t3 * 3 + t4 * 3 - t5) / 2)
+          + acc1 * ((t3 - t4 * 2 + t5) / 2)
+          + m1 * (t4 * 7 - t3 * 4 - t5 * 3)
+          + b.1 * (t3 * 10 - t4 * 15 + t5 * 6)
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        let one_t = <$t>::ONE - t;
+        let one_t2 = one_t * one_t;
+
+        u + (a - u) * one_t2 + (b - u) * t * t
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        let one_t = <$t>::ONE - t;
+        let one_t2 = one_t * one_t;
+        let one_t3 = one_t2 * one_t;
+        let t2 = t * t;
+
+        a * one_t3 + (u * one_t2 * t + v * one_t * t2) * 3 + b * t2 * t
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <Self as Interpolate<$t>>::cubic_bezier(t, a, u, b + b - v, b)
+      }
+    }
+
+    impl Norm<$t> for $t {
+      fn norm(self) -> $t {
+        self.abs()
+      }
+    }
+
+    impl MinMax for $t {
+      fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+      }
+    }
+  };
+}
+
+impl_Interpolate_fixed!(I8F24);
+impl_Interpolate_fixed!(I16F16);
+impl_Interpolate_fixed!(I32F32);
+impl_Interpolate_fixed!(I48F16);
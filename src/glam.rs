@@ -1,8 +1,236 @@
 use crate::impl_Interpolate;
-use glam::{Quat, Vec2, Vec3, Vec3A, Vec4};
+use crate::interpolate::{circular_arc, impl_circular_arc, Interpolate, MinMax, Norm};
+use crate::key::Key;
+use crate::quaternion::impl_Interpolate_quaternion;
+use crate::spline::circle_key_tangent;
+use glam::{DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Quat, Vec2, Vec3, Vec3A, Vec4};
 
-impl_Interpolate!(f32, Vec2, std::f32::consts::PI);
-impl_Interpolate!(f32, Vec3, std::f32::consts::PI);
-impl_Interpolate!(f32, Vec3A, std::f32::consts::PI);
-impl_Interpolate!(f32, Vec4, std::f32::consts::PI);
-impl_Interpolate!(f32, Quat, std::f32::consts::PI);
+impl_Interpolate!(f32, Vec2, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vec3, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vec3A, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vec4, std::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+
+impl_Interpolate!(f64, DVec2, std::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, DVec3, std::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, DVec4, std::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+
+impl_Interpolate_quaternion!(f32, Quat, Vec4, <[f32; 4]>::from, Quat::from_array);
+impl_Interpolate_quaternion!(f64, DQuat, DVec4, <[f64; 4]>::from, DQuat::from_array);
+
+// Integer vectors are interpolated as single-precision vectors, rounding the result to the nearest integer vector, so
+// that e.g. tile coordinates can be interpolated. Components are exactly converted as long as they don’t exceed 2²⁴ in
+// magnitude.
+macro_rules! impl_Interpolate_integer {
+  ($iv:ty, $v:ty, $as_v:ident, $as_iv:ident) => {
+    impl Interpolate<f32> for $iv {
+      fn step(t: f32, threshold: f32, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: f32, a: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::lerp(t, a.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn cosine(t: f32, a: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::cosine(t, a.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn smoothstep(t: f32, a: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::smoothstep(t, a.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn smootherstep(t: f32, a: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::smootherstep(t, a.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn cubic_hermite(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Self {
+        let v = |(t, iv): (f32, Self)| (t, iv.$as_v());
+        <$v as Interpolate<f32>>::cubic_hermite(t, v(x), v(a), v(b), v(y))
+          .round()
+          .$as_iv()
+      }
+
+      fn hermite(t: f32, a: (f32, Self), b: (f32, Self), m0: Self, m1: Self) -> Self {
+        let v = |(t, iv): (f32, Self)| (t, iv.$as_v());
+        <$v as Interpolate<f32>>::hermite(t, v(a), v(b), m0.$as_v(), m1.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn monotone_cubic(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Self {
+        let v = |(t, iv): (f32, Self)| (t, iv.$as_v());
+        <$v as Interpolate<f32>>::monotone_cubic(t, v(x), v(a), v(b), v(y))
+          .round()
+          .$as_iv()
+      }
+
+      fn akima(t: f32, keys: [(f32, Self); 6]) -> Self {
+        <$v as Interpolate<f32>>::akima(t, keys.map(|(t, iv)| (t, iv.$as_v())))
+          .round()
+          .$as_iv()
+      }
+
+      fn exponential(t: f32, a: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::exponential(t, a.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      // integer vectors cannot be differentiated, so their arcs follow the circle passing through the previous key
+      fn circular_arc<M>(t: f32, keys: &[Key<f32, Self, M>], i: usize) -> Self {
+        let tangent = circle_key_tangent(keys, i, |iv| iv.$as_v());
+        circular_arc(t, keys[i].value.$as_v(), keys[i + 1].value.$as_v(), tangent)
+          .round()
+          .$as_iv()
+      }
+
+      fn quintic_hermite(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Self {
+        let v = |(t, iv): (f32, Self)| (t, iv.$as_v());
+        <$v as Interpolate<f32>>::quintic_hermite(t, v(x), v(a), v(b), v(y))
+          .round()
+          .$as_iv()
+      }
+
+      fn kochanek_bartels(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+        tcb_a: (f32, f32, f32),
+        tcb_b: (f32, f32, f32),
+      ) -> Self {
+        let v = |(t, iv): (f32, Self)| (t, iv.$as_v());
+        <$v as Interpolate<f32>>::kochanek_bartels(t, v(x), v(a), v(b), v(y), tcb_a, tcb_b)
+          .round()
+          .$as_iv()
+      }
+
+      fn quadratic_bezier(t: f32, a: Self, u: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::quadratic_bezier(t, a.$as_v(), u.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn cubic_bezier(t: f32, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::cubic_bezier(t, a.$as_v(), u.$as_v(), v.$as_v(), b.$as_v())
+          .round()
+          .$as_iv()
+      }
+
+      fn cubic_bezier_mirrored(t: f32, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$v as Interpolate<f32>>::cubic_bezier_mirrored(
+          t,
+          a.$as_v(),
+          u.$as_v(),
+          v.$as_v(),
+          b.$as_v(),
+        )
+        .round()
+        .$as_iv()
+      }
+    }
+
+    impl Norm<f32> for $iv {
+      fn norm(self) -> f32 {
+        self.$as_v().length()
+      }
+    }
+  };
+}
+
+impl_Interpolate_integer!(IVec2, Vec2, as_vec2, as_ivec2);
+impl_Interpolate_integer!(IVec3, Vec3, as_vec3, as_ivec3);
+impl_Interpolate_integer!(IVec4, Vec4, as_vec4, as_ivec4);
+
+macro_rules! impl_Norm {
+  ($t:ty, $v:ty) => {
+    impl Norm<$t> for $v {
+      fn norm(self) -> $t {
+        self.length()
+      }
+    }
+  };
+}
+
+impl_Norm!(f32, Vec2);
+impl_Norm!(f32, Vec3);
+impl_Norm!(f32, Vec3A);
+impl_Norm!(f32, Vec4);
+impl_Norm!(f32, Quat);
+
+impl_Norm!(f64, DVec2);
+impl_Norm!(f64, DVec3);
+impl_Norm!(f64, DVec4);
+impl_Norm!(f64, DQuat);
+
+macro_rules! impl_MinMax {
+  ($v:ty) => {
+    impl MinMax for $v {
+      fn min(self, other: Self) -> Self {
+        <$v>::min(self, other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        <$v>::max(self, other)
+      }
+    }
+  };
+}
+
+impl_MinMax!(Vec2);
+impl_MinMax!(Vec3);
+impl_MinMax!(Vec3A);
+impl_MinMax!(Vec4);
+
+impl_MinMax!(DVec2);
+impl_MinMax!(DVec3);
+impl_MinMax!(DVec4);
+
+impl_MinMax!(IVec2);
+impl_MinMax!(IVec3);
+impl_MinMax!(IVec4);
@@ -0,0 +1,220 @@
+//! [half] implementors.
+//!
+//! Half-precision values are interpolated with single-precision sampling parameters. The arithmetic is computed in
+//! single precision: values are promoted to `f32`, interpolated, and the result is demoted back to half precision.
+//! Only the result is rounded, which prevents cubic and quintic modes from accumulating rounding errors – or
+//! overflowing – in their intermediate results, such as tangents.
+//!
+//! [half]: https://crates.io/crates/half
+
+use crate::interpolate::{Interpolate, MinMax, Norm};
+use half::{bf16, f16};
+
+macro_rules! impl_Interpolate_half {
+  ($h:ty) => {
+    impl Interpolate<f32> for $h {
+      fn step(t: f32, threshold: f32, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: f32, a: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::lerp(t, a.to_f32(), b.to_f32()))
+      }
+
+      fn cosine(t: f32, a: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::cosine(t, a.to_f32(), b.to_f32()))
+      }
+
+      fn smoothstep(t: f32, a: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::smoothstep(
+          t,
+          a.to_f32(),
+          b.to_f32(),
+        ))
+      }
+
+      fn smootherstep(t: f32, a: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::smootherstep(
+          t,
+          a.to_f32(),
+          b.to_f32(),
+        ))
+      }
+
+      fn cubic_hermite(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Self {
+        let f = |(t, h): (f32, Self)| (t, h.to_f32());
+        <$h>::from_f32(<f32 as Interpolate<f32>>::cubic_hermite(
+          t,
+          f(x),
+          f(a),
+          f(b),
+          f(y),
+        ))
+      }
+
+      fn hermite(t: f32, a: (f32, Self), b: (f32, Self), m0: Self, m1: Self) -> Self {
+        let f = |(t, h): (f32, Self)| (t, h.to_f32());
+        <$h>::from_f32(<f32 as Interpolate<f32>>::hermite(
+          t,
+          f(a),
+          f(b),
+          m0.to_f32(),
+          m1.to_f32(),
+        ))
+      }
+
+      fn monotone_cubic(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Self {
+        let f = |(t, h): (f32, Self)| (t, h.to_f32());
+        <$h>::from_f32(<f32 as Interpolate<f32>>::monotone_cubic(
+          t,
+          f(x),
+          f(a),
+          f(b),
+          f(y),
+        ))
+      }
+
+      fn monotone_tangents(
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Option<(Self, Self)> {
+        let f = |(t, h): (f32, Self)| (t, h.to_f32());
+        <f32 as Interpolate<f32>>::monotone_tangents(f(x), f(a), f(b), f(y))
+          .map(|(m0, m1)| (<$h>::from_f32(m0), <$h>::from_f32(m1)))
+      }
+
+      fn akima(t: f32, keys: [(f32, Self); 6]) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::akima(
+          t,
+          keys.map(|(t, h)| (t, h.to_f32())),
+        ))
+      }
+
+      fn akima_tangents(keys: [(f32, Self); 6]) -> Option<(Self, Self)> {
+        <f32 as Interpolate<f32>>::akima_tangents(keys.map(|(t, h)| (t, h.to_f32())))
+          .map(|(m0, m1)| (<$h>::from_f32(m0), <$h>::from_f32(m1)))
+      }
+
+      fn exponential(t: f32, a: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::exponential(
+          t,
+          a.to_f32(),
+          b.to_f32(),
+        ))
+      }
+
+      fn exponential_derivative(t: f32, a: Self, b: Self) -> Option<Self> {
+        <f32 as Interpolate<f32>>::exponential_derivative(t, a.to_f32(), b.to_f32())
+          .map(<$h>::from_f32)
+      }
+
+      fn quintic_hermite(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+      ) -> Self {
+        let f = |(t, h): (f32, Self)| (t, h.to_f32());
+        <$h>::from_f32(<f32 as Interpolate<f32>>::quintic_hermite(
+          t,
+          f(x),
+          f(a),
+          f(b),
+          f(y),
+        ))
+      }
+
+      fn kochanek_bartels(
+        t: f32,
+        x: (f32, Self),
+        a: (f32, Self),
+        b: (f32, Self),
+        y: (f32, Self),
+        tcb_a: (f32, f32, f32),
+        tcb_b: (f32, f32, f32),
+      ) -> Self {
+        let f = |(t, h): (f32, Self)| (t, h.to_f32());
+        <$h>::from_f32(<f32 as Interpolate<f32>>::kochanek_bartels(
+          t,
+          f(x),
+          f(a),
+          f(b),
+          f(y),
+          tcb_a,
+          tcb_b,
+        ))
+      }
+
+      fn quadratic_bezier(t: f32, a: Self, u: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::quadratic_bezier(
+          t,
+          a.to_f32(),
+          u.to_f32(),
+          b.to_f32(),
+        ))
+      }
+
+      fn cubic_bezier(t: f32, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::cubic_bezier(
+          t,
+          a.to_f32(),
+          u.to_f32(),
+          v.to_f32(),
+          b.to_f32(),
+        ))
+      }
+
+      fn cubic_bezier_mirrored(t: f32, a: Self, u: Self, v: Self, b: Self) -> Self {
+        <$h>::from_f32(<f32 as Interpolate<f32>>::cubic_bezier_mirrored(
+          t,
+          a.to_f32(),
+          u.to_f32(),
+          v.to_f32(),
+          b.to_f32(),
+        ))
+      }
+    }
+
+    impl Norm<f32> for $h {
+      fn norm(self) -> f32 {
+        self.to_f32().abs()
+      }
+
+      fn curvature(_: Self, second: Self) -> Option<f32> {
+        Some(second.to_f32())
+      }
+    }
+
+    impl MinMax for $h {
+      fn min(self, other: Self) -> Self {
+        <$h>::from_f32(f32::min(self.to_f32(), other.to_f32()))
+      }
+
+      fn max(self, other: Self) -> Self {
+        <$h>::from_f32(f32::max(self.to_f32(), other.to_f32()))
+      }
+    }
+  };
+}
+
+impl_Interpolate_half!(f16);
+impl_Interpolate_half!(bf16);
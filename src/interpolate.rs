@@ -28,18 +28,18 @@
 //! [`Trigo`]: crate::interpolate::Trigo
 //! [num-traits]: https://crates.io/crates/num-traits
 
+use crate::key::Key;
 #[cfg(not(feature = "std"))]
 use core::f32;
 #[cfg(not(feature = "std"))]
 use core::f64;
-// #[cfg(not(feature = "std"))]
-// use core::ops::{Add, Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 #[cfg(feature = "std")]
 use std::f32;
 #[cfg(feature = "std")]
 use std::f64;
 
-use num_traits::float::Float;
+use num_traits::float::{Float, FloatConst};
 
 /// Types that can be used as interpolator in splines.
 ///
@@ -76,6 +76,23 @@ pub trait Interpolate<T>: Sized + Copy {
   /// Cosine interpolation.
   fn cosine(t: T, a: Self, b: Self) -> Self;
 
+  /// Smoothstep interpolation, linearly interpolating with `t` eased by `3t² − 2t³`.
+  ///
+  /// The default implementation is the cubic Bézier curve with control points `a`, `a`, `b` and `b`, whose Bernstein
+  /// basis sums to that easing, as easing `t` directly requires arithmetic on `T`, which this trait doesn't require.
+  fn smoothstep(t: T, a: Self, b: Self) -> Self {
+    Self::cubic_bezier(t, a, a, b, b)
+  }
+
+  /// Smootherstep interpolation, linearly interpolating with `t` eased by `6t⁵ − 15t⁴ + 10t³`.
+  ///
+  /// The default implementation falls back to [`Interpolate::smoothstep`], which also has zero velocity at both ends,
+  /// as the quintic easing requires arithmetic on `T`, which this trait doesn't require. All implementors of this crate
+  /// override it.
+  fn smootherstep(t: T, a: Self, b: Self) -> Self {
+    Self::smoothstep(t, a, b)
+  }
+
   /// Cubic hermite interpolation.
   fn cubic_hermite(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self;
 
@@ -96,12 +113,576 @@ pub trait Interpolate<T>: Sized + Copy {
   /// inversing it (typical when the next point uses a Bézier interpolation, where input and output tangents are
   /// mirrored for the same key).
   fn cubic_bezier_mirrored(t: T, a: Self, u: Self, v: Self, b: Self) -> Self;
+
+  /// Kochanek–Bartels interpolation, a cubic Hermite interpolation with tension, continuity and bias control.
+  ///
+  /// Keys are passed the same way as for [`Interpolate::cubic_hermite`]. `tcb_a` contains the tension, continuity and
+  /// bias used to compute the outgoing tangent of `a`, and `tcb_b` the ones used to compute the incoming tangent of
+  /// `b`. When all of them are zero, this is the same as [`Interpolate::cubic_hermite`].
+  ///
+  /// The default implementation ignores tension, continuity and bias and falls back to
+  /// [`Interpolate::cubic_hermite`], as computing the tangents requires arithmetic on both `T` and the values, which
+  /// this trait doesn’t require. All implementors of this crate override it.
+  fn kochanek_bartels(
+    t: T,
+    x: (T, Self),
+    a: (T, Self),
+    b: (T, Self),
+    y: (T, Self),
+    tcb_a: (T, T, T),
+    tcb_b: (T, T, T),
+  ) -> Self {
+    let _ = (tcb_a, tcb_b);
+    Self::cubic_hermite(t, x, a, b, y)
+  }
+
+  /// Quintic Hermite interpolation, matching velocities and accelerations at both keys.
+  ///
+  /// Keys are passed the same way as for [`Interpolate::cubic_hermite`]. Velocities are estimated the same way, while
+  /// accelerations are estimated with the second divided difference of the neighbors of a key. If `a` (resp. `b`) has
+  /// no lower (resp. upper) neighbor, `x` (resp. `y`) is expected to be `a` (resp. `b`) itself, in which case a
+  /// one-sided difference is used for the velocity and the acceleration is zero.
+  ///
+  /// The default implementation falls back to [`Interpolate::cubic_hermite`], matching the velocities only, as
+  /// estimating accelerations requires arithmetic on both `T` and the values. All implementors of this crate override
+  /// it.
+  fn quintic_hermite(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self {
+    Self::cubic_hermite(t, x, a, b, y)
+  }
+
+  /// Cubic Hermite interpolation with explicit tangents.
+  ///
+  /// `a` and `b` are the keys of the segment, as sampling parameter and value. `m0` is the tangent of the curve when
+  /// leaving `a`, and `m1` its tangent when reaching `b`, both with respect to the sampling parameter.
+  fn hermite(t: T, a: (T, Self), b: (T, Self), m0: Self, m1: Self) -> Self;
+
+  /// Monotone cubic Hermite interpolation (Fritsch–Carlson).
+  ///
+  /// Arguments are the same as for [`Interpolate::cubic_hermite`]. If `a` (resp. `b`) has no lower (resp. upper)
+  /// neighbor, `x` (resp. `y`) is expected to be `a` (resp. `b`) itself, in which case a one-sided difference is used
+  /// to estimate the tangent.
+  ///
+  /// The default implementation falls back to [`Interpolate::cubic_hermite`], as values need to be ordered to limit
+  /// the tangents. Scalar implementors override it so that the curve never overshoots monotone data.
+  fn monotone_cubic(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self {
+    Self::cubic_hermite(t, x, a, b, y)
+  }
+
+  /// Tangents of `a` and `b` used by [`Interpolate::monotone_cubic`], with respect to the normalized sampling
+  /// parameter.
+  ///
+  /// The default implementation returns `None`, meaning that tangents are the same as the ones estimated by
+  /// [`Interpolate::cubic_hermite`].
+  fn monotone_tangents(
+    x: (T, Self),
+    a: (T, Self),
+    b: (T, Self),
+    y: (T, Self),
+  ) -> Option<(Self, Self)> {
+    let _ = (x, a, b, y);
+    None
+  }
+
+  /// Akima interpolation, a cubic Hermite interpolation with tangents that are less sensitive to outliers.
+  ///
+  /// `keys` contains the two lower neighbors of `a`, `a`, `b` and the two upper neighbors of `b`, in order. Missing
+  /// neighbors are expected to be replaced by the closest available key, as done with
+  /// [`Interpolate::monotone_cubic`].
+  ///
+  /// The default implementation falls back to [`Interpolate::cubic_hermite`] with the direct neighbors, as values need
+  /// absolute differences to compute the Akima weights. Scalar implementors override it.
+  fn akima(t: T, keys: [(T, Self); 6]) -> Self {
+    let [_, x, a, b, y, _] = keys;
+    Self::cubic_hermite(t, x, a, b, y)
+  }
+
+  /// Tangents of `a` and `b` used by [`Interpolate::akima`], with respect to the normalized sampling parameter.
+  ///
+  /// The default implementation returns `None`, meaning that tangents are the same as the ones estimated by
+  /// [`Interpolate::cubic_hermite`].
+  fn akima_tangents(keys: [(T, Self); 6]) -> Option<(Self, Self)> {
+    let _ = keys;
+    None
+  }
+
+  /// Exponential interpolation, linearly interpolating the logarithms of `a` and `b`.
+  ///
+  /// The result is the weighted geometric mean of `a` and `b`, which only makes sense for strictly positive values.
+  ///
+  /// The default implementation falls back to [`Interpolate::lerp`]. Scalar implementors override it, and
+  /// debug-assert that `a` and `b` are strictly positive.
+  fn exponential(t: T, a: Self, b: Self) -> Self {
+    Self::lerp(t, a, b)
+  }
+
+  /// Derivative of [`Interpolate::exponential`] with respect to `t`.
+  ///
+  /// The default implementation returns `None`, meaning that the derivative is the one of a linear interpolation,
+  /// `b - a`.
+  fn exponential_derivative(t: T, a: Self, b: Self) -> Option<Self> {
+    let _ = (t, a, b);
+    None
+  }
+
+  /// Circular arc interpolation of the segment going from the key at index `i` in `keys` to the next one, along the
+  /// circle passing through both keys and tangent, at the first one, to the end of the previous segment.
+  ///
+  /// The arc is swept with a constant angular velocity. The keys are passed, rather than the values of the segment,
+  /// because the tangent depends on the interpolation mode of the previous segment: see
+  /// [`Interpolation::CircularArc`].
+  ///
+  /// The default implementation falls back to [`Interpolate::lerp`], as a norm is needed to compute angles. Vector
+  /// implementors override it with [`circular_arc`].
+  ///
+  /// [`Interpolation::CircularArc`]: crate::interpolation::Interpolation::CircularArc
+  fn circular_arc<M>(t: T, keys: &[Key<T, Self, M>], i: usize) -> Self {
+    Self::lerp(t, keys[i].value, keys[i + 1].value)
+  }
+
+  /// First and second derivatives of [`Interpolate::circular_arc`] with respect to `t`.
+  ///
+  /// The default implementation returns `None`, meaning that the derivatives are the ones of a linear interpolation,
+  /// `b - a` and zero.
+  fn circular_arc_derivatives<M>(t: T, keys: &[Key<T, Self, M>], i: usize) -> Option<(Self, Self)> {
+    let _ = (t, keys, i);
+    None
+  }
+
+  /// Derivative of [`Interpolate::lerp`] with respect to `t`.
+  ///
+  /// The default implementation returns `None`, meaning that the interpolation is actually linear and its derivative
+  /// is `b - a`. Implementors interpolating along a curve – e.g. quaternions, along a great circle with slerp – override
+  /// it, so that the derivatives of [`Interpolation::Linear`], [`Interpolation::Cosine`], [`Interpolation::Smoothstep`]
+  /// and [`Interpolation::Smootherstep`] segments follow the sampled curve.
+  ///
+  /// [`Interpolation::Linear`]: crate::interpolation::Interpolation::Linear
+  /// [`Interpolation::Cosine`]: crate::interpolation::Interpolation::Cosine
+  /// [`Interpolation::Smoothstep`]: crate::interpolation::Interpolation::Smoothstep
+  /// [`Interpolation::Smootherstep`]: crate::interpolation::Interpolation::Smootherstep
+  fn lerp_derivative(t: T, a: Self, b: Self) -> Option<Self> {
+    let _ = (t, a, b);
+    None
+  }
+
+  /// Derivative of [`Interpolate::cubic_hermite`] with respect to `t`.
+  ///
+  /// The default implementation returns `None`, meaning that the interpolation is the cubic Hermite curve with
+  /// Catmull-Rom tangents, which [`Interpolation::CatmullRom`] segments differentiate themselves. Implementors
+  /// interpolating along another curve – e.g. quaternions, with squad – override it.
+  ///
+  /// [`Interpolation::CatmullRom`]: crate::interpolation::Interpolation::CatmullRom
+  fn cubic_hermite_derivative(
+    t: T,
+    x: (T, Self),
+    a: (T, Self),
+    b: (T, Self),
+    y: (T, Self),
+  ) -> Option<Self> {
+    let _ = (t, x, a, b, y);
+    None
+  }
+}
+
+/// Values supporting linear combinations with `T`.
+///
+/// This trait is automatically implemented for every type that can be added, subtracted, and multiplied and divided by
+/// `T`. It unlocks operations on splines that need more than sampling, such as differentiation.
+pub trait Linear<T>:
+  Sized
+  + Copy
+  + Add<Output = Self>
+  + Sub<Output = Self>
+  + Mul<T, Output = Self>
+  + Div<T, Output = Self>
+{
+}
+
+impl<T, V> Linear<T> for V where
+  V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V> + Div<T, Output = V>
+{
+}
+
+/// Fritsch–Carlson monotone cubic Hermite interpolation for scalars.
+pub(crate) fn monotone_cubic<V>(t: V, x: (V, V), a: (V, V), b: (V, V), y: (V, V)) -> V
+where
+  V: Float,
+{
+  let two = V::one() + V::one();
+  let three = two + V::one();
+  let (m0, m1) = monotone_tangents(x, a, b, y);
+
+  let t2 = t * t;
+  let t3 = t2 * t;
+
+  a.1 * (two * t3 - three * t2 + V::one())
+    + m0 * (t3 - two * t2 + t)
+    + b.1 * (three * t2 - two * t3)
+    + m1 * (t3 - t2)
+}
+
+/// Fritsch–Carlson tangents of `a` and `b`, with respect to the normalized sampling parameter.
+///
+/// Tangents are estimated by averaging the secants on each side of a key, then set to zero on local extrema and
+/// limited to three times the smallest adjacent secant, which is sufficient for the segment to stay monotone. The
+/// limit only depends on the key’s neighborhood, so the resulting curve is C1.
+pub(crate) fn monotone_tangents<V>(x: (V, V), a: (V, V), b: (V, V), y: (V, V)) -> (V, V)
+where
+  V: Float,
+{
+  let two = V::one() + V::one();
+  let three = two + V::one();
+
+  let h = b.0 - a.0;
+  let delta = (b.1 - a.1) / h;
+  let delta_x = if x.0 < a.0 {
+    (a.1 - x.1) / (a.0 - x.0)
+  } else {
+    delta
+  };
+  let delta_y = if y.0 > b.0 {
+    (y.1 - b.1) / (y.0 - b.0)
+  } else {
+    delta
+  };
+
+  let tangent = |d0: V, d1: V| {
+    if d0 * d1 <= V::zero() {
+      V::zero()
+    } else {
+      let m = (d0 + d1) / two;
+      let limit = three * d0.abs().min(d1.abs());
+      m.signum() * m.abs().min(limit)
+    }
+  };
+
+  (tangent(delta_x, delta) * h, tangent(delta, delta_y) * h)
+}
+
+/// Exponential interpolation for strictly positive scalars.
+pub(crate) fn exponential<V>(t: V, a: V, b: V) -> V
+where
+  V: Float,
+{
+  debug_assert!(
+    a > V::zero() && b > V::zero(),
+    "exponential interpolation requires strictly positive values"
+  );
+
+  let (ln_a, ln_b) = (a.ln(), b.ln());
+  (ln_a + (ln_b - ln_a) * t).exp()
+}
+
+/// Akima cubic Hermite interpolation for scalars.
+pub(crate) fn akima<V>(t: V, keys: [(V, V); 6]) -> V
+where
+  V: Float,
+{
+  let two = V::one() + V::one();
+  let three = two + V::one();
+  let (a, b) = (keys[2], keys[3]);
+  let (m0, m1) = akima_tangents(keys);
+
+  let t2 = t * t;
+  let t3 = t2 * t;
+
+  a.1 * (two * t3 - three * t2 + V::one())
+    + m0 * (t3 - two * t2 + t)
+    + b.1 * (three * t2 - two * t3)
+    + m1 * (t3 - t2)
+}
+
+/// Akima tangents of `a` and `b`, with respect to the normalized sampling parameter.
+///
+/// The tangent of a key is the average of the slopes of its two adjacent segments, weighted by the differences of the
+/// slopes on the opposite side. Slopes of missing segments are linearly extrapolated from the two closest ones, as
+/// proposed by Akima, so that only two keys are required.
+pub(crate) fn akima_tangents<V>(keys: [(V, V); 6]) -> (V, V)
+where
+  V: Float,
+{
+  let two = V::one() + V::one();
+  let slope = |p: (V, V), q: (V, V)| {
+    if p.0 < q.0 {
+      Some((q.1 - p.1) / (q.0 - p.0))
+    } else {
+      None
+    }
+  };
+
+  let m2 = (keys[3].1 - keys[2].1) / (keys[3].0 - keys[2].0);
+  let (m1, m3) = match (slope(keys[1], keys[2]), slope(keys[3], keys[4])) {
+    (Some(m1), Some(m3)) => (m1, m3),
+    (Some(m1), None) => (m1, two * m2 - m1),
+    (None, Some(m3)) => (two * m2 - m3, m3),
+    (None, None) => (m2, m2),
+  };
+  let m0 = slope(keys[0], keys[1]).unwrap_or(two * m1 - m2);
+  let m4 = slope(keys[4], keys[5]).unwrap_or(two * m3 - m2);
+
+  let tangent = |m0: V, m1: V, m2: V, m3: V| {
+    let w0 = (m3 - m2).abs();
+    let w1 = (m1 - m0).abs();
+
+    if w0 + w1 > V::zero() {
+      (w0 * m1 + w1 * m2) / (w0 + w1)
+    } else {
+      (m1 + m2) / two
+    }
+  };
+
+  let h = keys[3].0 - keys[2].0;
+  (tangent(m0, m1, m2, m3) * h, tangent(m1, m2, m3, m4) * h)
+}
+
+// Dot product of two vectors, computed from their norm with the polarization identity.
+pub(crate) fn dot<T, V>(u: V, v: V) -> T
+where
+  T: Float,
+  V: Linear<T> + Norm<T>,
+{
+  let four = T::from(4.).unwrap();
+  let (sum, diff) = ((u + v).norm(), (u - v).norm());
+
+  (sum * sum - diff * diff) / four
+}
+
+// Geometry of the arc going from `a` to `b` along the circle passing through both and tangent to `tangent` at `a`:
+// radius, unit vector pointing from `a` towards the center, unit tangent at `a` and angle swept from `a` to `b`.
+// `None` if `b` lies on the line going through `a` along `tangent`.
+fn arc<T, V>(a: V, b: V, tangent: V) -> Option<(T, V, V, T)>
+where
+  T: Float + FloatConst,
+  V: Linear<T> + Norm<T>,
+{
+  let two = T::one() + T::one();
+  let u = b - a;
+  let (norm_u, norm_tangent) = (u.norm(), tangent.norm());
+
+  if norm_u <= T::zero() || norm_tangent <= T::zero() {
+    return None;
+  }
+
+  // decompose the chord along the tangent and along the normal, which points towards the center
+  let e2 = tangent / norm_tangent;
+  let along = dot(u, e2);
+  let ortho = u - e2 * along;
+  let norm_ortho = ortho.norm();
+
+  if norm_ortho <= T::epsilon().sqrt() * norm_u {
+    return None;
+  }
+
+  let e1 = ortho / norm_ortho;
+  let radius = norm_u * norm_u / (two * norm_ortho);
+
+  // angle of b around the center, counted from a in the direction of the tangent
+  let sweep = along.atan2(radius - norm_ortho);
+  let sweep = if sweep < T::zero() {
+    sweep + two * T::PI()
+  } else {
+    sweep
+  };
+
+  Some((radius, e1, e2, sweep))
+}
+
+/// Circular arc interpolation between `a` and `b`, along the circle passing through both and tangent to `tangent` at
+/// `a`.
+///
+/// The arc leaves `a` in the direction of `tangent` – whose norm doesn’t matter – and is swept with a constant angular
+/// velocity. If `b` lies on the line going through `a` along `tangent`, or if `tangent` is zero, `a` and `b` are
+/// linearly interpolated instead.
+///
+/// This is the implementation of [`Interpolate::circular_arc`] for vector types, `tangent` being the derivative at
+/// the end of the previous segment.
+pub fn circular_arc<T, V>(t: T, a: V, b: V, tangent: V) -> V
+where
+  T: Float + FloatConst,
+  V: Linear<T> + Norm<T>,
+{
+  match arc(a, b, tangent) {
+    Some((radius, e1, e2, sweep)) => {
+      let angle = sweep * t;
+      a + (e1 * (T::one() - angle.cos()) + e2 * angle.sin()) * radius
+    }
+
+    None => a + (b - a) * t,
+  }
+}
+
+/// First and second derivatives of [`circular_arc`] with respect to `t`.
+///
+/// This is the implementation of [`Interpolate::circular_arc_derivatives`] for vector types.
+pub fn circular_arc_derivatives<T, V>(t: T, a: V, b: V, tangent: V) -> (V, V)
+where
+  T: Float + FloatConst,
+  V: Linear<T> + Norm<T>,
+{
+  match arc(a, b, tangent) {
+    Some((radius, e1, e2, sweep)) => {
+      let angle = sweep * t;
+      let (sin, cos) = angle.sin_cos();
+
+      (
+        (e1 * sin + e2 * cos) * (radius * sweep),
+        (e1 * cos - e2 * sin) * (radius * sweep * sweep),
+      )
+    }
+
+    None => (b - a, (b - a) * T::zero()),
+  }
+}
+
+// Tangent at `a` of the circle passing through `a`, `b` and `p`, oriented towards `b` along the arc that doesn’t
+// contain `p`: the inversion centered at `a` maps that circle to the line going through the images of `b` and `p`,
+// which is parallel to the tangent. `b - a` if the circle is undefined.
+#[allow(dead_code)]
+pub(crate) fn circle_tangent<T, V>(a: V, b: V, p: V) -> V
+where
+  T: Float,
+  V: Linear<T> + Norm<T>,
+{
+  let inverse = |v: V| {
+    let norm = v.norm();
+    v / (norm * norm)
+  };
+  let (u, w) = (b - a, p - a);
+
+  if u.norm() <= T::zero() || w.norm() <= T::zero() {
+    return u;
+  }
+
+  let tangent = inverse(u) - inverse(w);
+
+  if tangent.norm() > T::zero() {
+    tangent
+  } else {
+    u
+  }
+}
+
+// Implementation of Interpolate::circular_arc and Interpolate::circular_arc_derivatives for vector types, meant to be
+// passed to impl_Interpolate!.
+#[allow(unused_macros)]
+macro_rules! impl_circular_arc {
+  ($t:ty) => {
+    fn circular_arc<M>(t: $t, keys: &[$crate::key::Key<$t, Self, M>], i: usize) -> Self {
+      let tangent = $crate::spline::circular_arc_tangent(keys, i);
+      $crate::interpolate::circular_arc(t, keys[i].value, keys[i + 1].value, tangent)
+    }
+
+    fn circular_arc_derivatives<M>(
+      t: $t,
+      keys: &[$crate::key::Key<$t, Self, M>],
+      i: usize,
+    ) -> Option<(Self, Self)> {
+      let tangent = $crate::spline::circular_arc_tangent(keys, i);
+      Some($crate::interpolate::circular_arc_derivatives(
+        t,
+        keys[i].value,
+        keys[i + 1].value,
+        tangent,
+      ))
+    }
+  };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_circular_arc;
+
+/// Values that have a norm (i.e. a length), expressed with `T`.
+///
+/// This trait is required by operations on splines that measure distances, such as computing the arc length. It is
+/// implemented for scalars (absolute value) and for the vector types of the supported math crates (Euclidean norm).
+pub trait Norm<T> {
+  /// Norm of the value.
+  fn norm(self) -> T;
+
+  /// Curvature of a curve, given its first and second derivatives at a point.
+  ///
+  /// The default implementation computes the unsigned curvature `|r' × r''| / |r'|³` of curves of any dimension, and
+  /// returns `None` if the first derivative is zero. Scalars override it to return the signed second derivative.
+  fn curvature(first: Self, second: Self) -> Option<T>
+  where
+    Self: Linear<T>,
+    T: Float,
+  {
+    let speed = first.norm();
+
+    if speed == T::zero() {
+      return None;
+    }
+
+    let acceleration = second.norm();
+    let projection = dot(first, second);
+    let cross =
+      (speed * speed * acceleration * acceleration - projection * projection).max(T::zero());
+
+    Some(cross.sqrt() / (speed * speed * speed))
+  }
+}
+
+impl Norm<f32> for f32 {
+  fn norm(self) -> f32 {
+    self.abs()
+  }
+
+  fn curvature(_: Self, second: Self) -> Option<f32> {
+    Some(second)
+  }
+}
+
+impl Norm<f64> for f64 {
+  fn norm(self) -> f64 {
+    self.abs()
+  }
+
+  fn curvature(_: Self, second: Self) -> Option<f64> {
+    Some(second)
+  }
+}
+
+/// Values that can be compared component-wise.
+///
+/// This trait is required by operations on splines that compute bounds, such as the bounding box. It is implemented
+/// for scalars and for the vector types of the supported math crates.
+pub trait MinMax {
+  /// Component-wise minimum of two values.
+  fn min(self, other: Self) -> Self;
+
+  /// Component-wise maximum of two values.
+  fn max(self, other: Self) -> Self;
+}
+
+impl MinMax for f32 {
+  fn min(self, other: Self) -> Self {
+    f32::min(self, other)
+  }
+
+  fn max(self, other: Self) -> Self {
+    f32::max(self, other)
+  }
+}
+
+impl MinMax for f64 {
+  fn min(self, other: Self) -> Self {
+    f64::min(self, other)
+  }
+
+  fn max(self, other: Self) -> Self {
+    f64::max(self, other)
+  }
 }
 
 #[macro_export]
 macro_rules! impl_Interpolate {
-  ($t:ty, $v:ty, $pi:expr) => {
-    impl $crate::interpolate::Interpolate<$t> for $v {
+  // Generic parameters of the value type – e.g. units – can be passed first, as in `<U> f32, Vector<f32, U>, PI`.
+  (<$($g:ident),*> $t:ty, $v:ty, $pi:expr) => {
+    $crate::impl_Interpolate!(<$($g),*> $t, $v, $pi, {});
+  };
+
+  (<$($g:ident),*> $t:ty, $v:ty, $pi:expr, { $($extra:tt)* }) => {
+    impl<$($g),*> $crate::interpolate::Interpolate<$t> for $v {
       fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
         if t < threshold {
           a
@@ -115,6 +696,11 @@ macro_rules! impl_Interpolate {
         <Self as $crate::interpolate::Interpolate<$t>>::lerp(cos_nt, a, b)
       }
 
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        let eased_t = t * t * t * (t * (t * 6. - 15.) + 10.);
+        <Self as $crate::interpolate::Interpolate<$t>>::lerp(eased_t, a, b)
+      }
+
       fn lerp(t: $t, a: Self, b: Self) -> Self {
         a * (1. - t) + b * t
       }
@@ -139,6 +725,90 @@ macro_rules! impl_Interpolate {
           + m1 * (t3 - t2)
       }
 
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        let two_t = t * 2.;
+        let three_t = t * 3.;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two_t3 = t2 * two_t;
+        let two_t2 = t * two_t;
+        let three_t2 = t * three_t;
+
+        // tangents, with respect to the normalized sampling parameter
+        let m0 = m0 * (b.0 - a.0);
+        let m1 = m1 * (b.0 - a.0);
+
+        a.1 * (two_t3 - three_t2 + 1.)
+          + m0 * (t3 - two_t2 + t)
+          + b.1 * (three_t2 - two_t3)
+          + m1 * (t3 - t2)
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        let two_t = t * 2.;
+        let three_t = t * 3.;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two_t3 = t2 * two_t;
+        let two_t2 = t * two_t;
+        let three_t2 = t * three_t;
+
+        // tangents
+        let (tension, continuity, bias) = tcb_a;
+        let m0 = ((a.1 - x.1) * ((1. + bias) * (1. + continuity))
+          + (b.1 - a.1) * ((1. - bias) * (1. - continuity)))
+          * ((1. - tension) / (b.0 - x.0) * (b.0 - a.0));
+        let (tension, continuity, bias) = tcb_b;
+        let m1 = ((b.1 - a.1) * ((1. + bias) * (1. - continuity))
+          + (y.1 - b.1) * ((1. - bias) * (1. + continuity)))
+          * ((1. - tension) / (y.0 - a.0) * (b.0 - a.0));
+
+        a.1 * (two_t3 - three_t2 + 1.)
+          + m0 * (t3 - two_t2 + t)
+          + b.1 * (three_t2 - two_t3)
+          + m1 * (t3 - t2)
+      }
+
+      fn quintic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+        let t5 = t4 * t;
+
+        // velocities
+        let h = b.0 - a.0;
+        let m0 = (b.1 - x.1) / (b.0 - x.0) * h;
+        let m1 = (y.1 - a.1) / (y.0 - a.0) * h;
+
+        // accelerations
+        let slope = (b.1 - a.1) / h;
+        let acc0 = if x.0 < a.0 {
+          (slope - (a.1 - x.1) / (a.0 - x.0)) * (2. / (b.0 - x.0) * h * h)
+        } else {
+          a.1 * 0.
+        };
+        let acc1 = if y.0 > b.0 {
+          ((y.1 - b.1) / (y.0 - b.0) - slope) * (2. / (y.0 - a.0) * h * h)
+        } else {
+          b.1 * 0.
+        };
+
+        a.1 * (1. - 10. * t3 + 15. * t4 - 6. * t5)
+          + m0 * (t - 6. * t3 + 8. * t4 - 3. * t5)
+          + acc0 * (0.5 * t2 - 1.5 * t3 + 1.5 * t4 - 0.5 * t5)
+          + acc1 * (0.5 * t3 - t4 + 0.5 * t5)
+          + m1 * (7. * t4 - 4. * t3 - 3. * t5)
+          + b.1 * (10. * t3 - 15. * t4 + 6. * t5)
+      }
+
       fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
         let one_t = 1. - t;
         let one_t2 = one_t * one_t;
@@ -158,13 +828,27 @@ macro_rules! impl_Interpolate {
       fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
         <Self as $crate::interpolate::Interpolate<$t>>::cubic_bezier(t, a, u, b + b - v, b)
       }
+
+      $($extra)*
     }
   };
+
+  ($t:ty, $v:ty, $pi:expr) => {
+    $crate::impl_Interpolate!(<> $t, $v, $pi, {});
+  };
+
+  ($t:ty, $v:ty, $pi:expr, { $($extra:tt)* }) => {
+    $crate::impl_Interpolate!(<> $t, $v, $pi, { $($extra)* });
+  };
 }
 
 #[macro_export]
 macro_rules! impl_InterpolateT {
   ($t:ty, $v:ty, $pi:expr) => {
+    $crate::impl_InterpolateT!($t, $v, $pi, {});
+  };
+
+  ($t:ty, $v:ty, $pi:expr, { $($extra:tt)* }) => {
     impl $crate::interpolate::Interpolate<$t> for $v {
       fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
         if t < threshold {
@@ -179,6 +863,11 @@ macro_rules! impl_InterpolateT {
         <Self as $crate::interpolate::Interpolate<$t>>::lerp(cos_nt, a, b)
       }
 
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        let eased_t = t * t * t * (t * (t * 6. - 15.) + 10.);
+        <Self as $crate::interpolate::Interpolate<$t>>::lerp(eased_t, a, b)
+      }
+
       fn lerp(t: $t, a: Self, b: Self) -> Self {
         let t = Self::from(t);
         a * (1. - t) + b * t
@@ -205,6 +894,93 @@ macro_rules! impl_InterpolateT {
           + m1 * (t3 - t2)
       }
 
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        let t = Self::from(t);
+        let two_t = t * 2.;
+        let three_t = t * 3.;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two_t3 = t2 * two_t;
+        let two_t2 = t * two_t;
+        let three_t2 = t * three_t;
+
+        // tangents, with respect to the normalized sampling parameter
+        let m0 = m0 * Self::from(b.0 - a.0);
+        let m1 = m1 * Self::from(b.0 - a.0);
+
+        a.1 * (two_t3 - three_t2 + 1.)
+          + m0 * (t3 - two_t2 + t)
+          + b.1 * (three_t2 - two_t3)
+          + m1 * (t3 - t2)
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        let t = Self::from(t);
+        let two_t = t * 2.;
+        let three_t = t * 3.;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let two_t3 = t2 * two_t;
+        let two_t2 = t * two_t;
+        let three_t2 = t * three_t;
+
+        // tangents
+        let (tension, continuity, bias) = tcb_a;
+        let m0 = ((a.1 - x.1) * Self::from((1. + bias) * (1. + continuity))
+          + (b.1 - a.1) * Self::from((1. - bias) * (1. - continuity)))
+          * Self::from((1. - tension) / (b.0 - x.0) * (b.0 - a.0));
+        let (tension, continuity, bias) = tcb_b;
+        let m1 = ((b.1 - a.1) * Self::from((1. + bias) * (1. - continuity))
+          + (y.1 - b.1) * Self::from((1. - bias) * (1. + continuity)))
+          * Self::from((1. - tension) / (y.0 - a.0) * (b.0 - a.0));
+
+        a.1 * (two_t3 - three_t2 + 1.)
+          + m0 * (t3 - two_t2 + t)
+          + b.1 * (three_t2 - two_t3)
+          + m1 * (t3 - t2)
+      }
+
+      fn quintic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        let t = Self::from(t);
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+        let t5 = t4 * t;
+
+        // velocities
+        let h = Self::from(b.0 - a.0);
+        let m0 = (b.1 - x.1) / (Self::from(b.0 - x.0)) * h;
+        let m1 = (y.1 - a.1) / (Self::from(y.0 - a.0)) * h;
+
+        // accelerations
+        let slope = (b.1 - a.1) / h;
+        let acc0 = if x.0 < a.0 {
+          (slope - (a.1 - x.1) / Self::from(a.0 - x.0)) * (2. / Self::from(b.0 - x.0) * h * h)
+        } else {
+          a.1 * 0.
+        };
+        let acc1 = if y.0 > b.0 {
+          ((y.1 - b.1) / Self::from(y.0 - b.0) - slope) * (2. / Self::from(y.0 - a.0) * h * h)
+        } else {
+          b.1 * 0.
+        };
+
+        a.1 * (1. - 10. * t3 + 15. * t4 - 6. * t5)
+          + m0 * (t - 6. * t3 + 8. * t4 - 3. * t5)
+          + acc0 * (0.5 * t2 - 1.5 * t3 + 1.5 * t4 - 0.5 * t5)
+          + acc1 * (0.5 * t3 - t4 + 0.5 * t5)
+          + m1 * (7. * t4 - 4. * t3 - 3. * t5)
+          + b.1 * (10. * t3 - 15. * t4 + 6. * t5)
+      }
+
       fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
         let t = Self::from(t);
         let one_t = 1. - t;
@@ -226,10 +1002,63 @@ macro_rules! impl_InterpolateT {
       fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
         <Self as $crate::interpolate::Interpolate<$t>>::cubic_bezier(t, a, u, b + b - v, b)
       }
+
+      $($extra)*
+    }
+  };
+}
+
+// Methods only available to scalars, as they require ordering values.
+macro_rules! impl_Interpolate_scalar {
+  ($t:ty) => {
+    fn monotone_cubic(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+      monotone_cubic(
+        Self::from(t),
+        (Self::from(x.0), x.1),
+        (Self::from(a.0), a.1),
+        (Self::from(b.0), b.1),
+        (Self::from(y.0), y.1),
+      )
+    }
+
+    fn monotone_tangents(
+      x: ($t, Self),
+      a: ($t, Self),
+      b: ($t, Self),
+      y: ($t, Self),
+    ) -> Option<(Self, Self)> {
+      Some(monotone_tangents(
+        (Self::from(x.0), x.1),
+        (Self::from(a.0), a.1),
+        (Self::from(b.0), b.1),
+        (Self::from(y.0), y.1),
+      ))
+    }
+
+    fn akima(t: $t, keys: [($t, Self); 6]) -> Self {
+      akima(Self::from(t), keys.map(|(t, v)| (Self::from(t), v)))
+    }
+
+    fn akima_tangents(keys: [($t, Self); 6]) -> Option<(Self, Self)> {
+      Some(akima_tangents(keys.map(|(t, v)| (Self::from(t), v))))
+    }
+
+    fn exponential(t: $t, a: Self, b: Self) -> Self {
+      exponential(Self::from(t), a, b)
+    }
+
+    fn exponential_derivative(t: $t, a: Self, b: Self) -> Option<Self> {
+      Some(exponential(Self::from(t), a, b) * (b.ln() - a.ln()))
     }
   };
 }
 
-impl_Interpolate!(f32, f32, core::f32::consts::PI);
-impl_Interpolate!(f64, f64, core::f64::consts::PI);
-impl_InterpolateT!(f32, f64, core::f32::consts::PI);
+impl_Interpolate!(f32, f32, core::f32::consts::PI, {
+  impl_Interpolate_scalar!(f32);
+});
+impl_Interpolate!(f64, f64, core::f64::consts::PI, {
+  impl_Interpolate_scalar!(f64);
+});
+impl_InterpolateT!(f32, f64, core::f32::consts::PI, {
+  impl_Interpolate_scalar!(f32);
+});
@@ -7,12 +7,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// Feel free to visit each variant for more documentation.
 #[non_exhaustive]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(
   any(feature = "serialization", feature = "serde"),
   derive(Deserialize, Serialize),
   serde(rename_all = "snake_case")
 )]
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
 pub enum Interpolation<T, V> {
   /// Hold a [`Key`] until the sampling value passes the normalized step threshold, in which
   /// case the next key is used.
@@ -31,9 +32,109 @@ pub enum Interpolation<T, V> {
   /// Cosine interpolation between a key and the next one.
   Cosine,
 
+  /// Smoothstep interpolation between a key and the next one.
+  ///
+  /// The normalized sampling parameter is eased with `3t² − 2t³` before linearly interpolating, which yields a zero
+  /// derivative at both keys. This is cheaper than [`Interpolation::Cosine`], for a similar shape.
+  Smoothstep,
+
+  /// Smootherstep interpolation between a key and the next one.
+  ///
+  /// The normalized sampling parameter is eased with `6t⁵ − 15t⁴ + 10t³` before linearly interpolating, which yields
+  /// zero first and second derivatives at both keys.
+  Smootherstep,
+
   /// Catmull-Rom interpolation, performing a cubic Hermite interpolation using four keys.
   CatmullRom,
 
+  /// Kochanek–Bartels interpolation, performing a cubic Hermite interpolation using four keys and tangents
+  /// controlled by a _tension_, a _continuity_ and a _bias_.
+  ///
+  /// - The tension changes the length of the tangents: `1` yields a sharp hold at the key, `-1` a rounder curve.
+  /// - The continuity changes how the incoming and outgoing tangents differ: non-zero values create corners.
+  /// - The bias changes the direction of the tangents: `-1` favors the next key, `1` the previous one.
+  ///
+  /// The outgoing tangent of this key is computed with its own parameters, while the incoming tangent of the next key
+  /// is computed with the ones of the next key if it also uses this interpolation mode, or with the ones of this key
+  /// otherwise. When all the parameters are zero, this is the same as [`Interpolation::CatmullRom`].
+  ///
+  /// Contrary to [`Interpolation::CatmullRom`], this interpolation mode doesn’t require four keys: missing neighbors
+  /// on the first and last segments are clamped to the keys of the segment.
+  TCB {
+    /// Tension of the curve at the key.
+    tension: T,
+    /// Continuity of the curve at the key.
+    continuity: T,
+    /// Bias of the curve at the key.
+    bias: T,
+  },
+
+  /// Monotone cubic interpolation, performing a cubic Hermite interpolation with tangents computed with the
+  /// Fritsch–Carlson method.
+  ///
+  /// Contrary to [`Interpolation::CatmullRom`], the curve never overshoots monotone data: sampled values always lie
+  /// between the values of the two keys of the segment. The tangents are estimated from up to one neighbor on each
+  /// side of the segment; on the first and last segments, one-sided differences are used instead, so that only two
+  /// keys are required.
+  ///
+  /// Only scalar implementors of [`Interpolate`] actually limit the tangents; other types fall back to a regular
+  /// cubic Hermite interpolation.
+  ///
+  /// [`Interpolate`]: crate::interpolate::Interpolate
+  MonotoneCubic,
+
+  /// Akima interpolation, performing a cubic Hermite interpolation with tangents computed with the Akima method.
+  ///
+  /// The tangent of a key is a weighted average of the slopes of its two adjacent segments, where the weights depend on
+  /// the slopes of the segments around them. Contrary to [`Interpolation::CatmullRom`], a single outlier only
+  /// affects the curve in its direct neighborhood, which avoids wild swings around it. The tangents are estimated from
+  /// up to two neighbors on each side of the segment; on the first and last segments, the slopes of missing segments
+  /// are linearly extrapolated from the two closest ones, so that only two keys are required.
+  ///
+  /// Only scalar implementors of [`Interpolate`] actually compute the Akima tangents; other types fall back to a
+  /// regular cubic Hermite interpolation.
+  ///
+  /// [`Interpolate`]: crate::interpolate::Interpolate
+  Akima,
+
+  /// Quintic Hermite interpolation, performing a degree-5 Hermite interpolation using four keys.
+  ///
+  /// Velocities at keys are estimated as for [`Interpolation::CatmullRom`], and accelerations with the second
+  /// divided differences of the neighbors of the keys. Because both only depend on the direct neighbors of a key, they
+  /// are shared by the segments on both sides of it: the curve is then C2, i.e. its acceleration is continuous.
+  ///
+  /// This interpolation mode doesn’t require four keys: on the first and last segments, missing neighbors are clamped
+  /// to the keys of the segment, which yields a one-sided velocity and a zero acceleration.
+  QuinticHermite,
+
+  /// Exponential interpolation, linearly interpolating the logarithms of the values of the keys.
+  ///
+  /// This is suited to values that are perceived logarithmically, such as zoom levels or loudness: the value sampled
+  /// in the middle of a segment is the geometric mean of the values of its keys. Values must be strictly positive.
+  ///
+  /// Only scalar implementors of [`Interpolate`] actually interpolate exponentially; other types fall back to a linear
+  /// interpolation.
+  ///
+  /// [`Interpolate`]: crate::interpolate::Interpolate
+  Exponential,
+
+  /// Circular arc interpolation, tracing an arc of circle from a key to the next one.
+  ///
+  /// The circle is the one passing through both keys of the segment and tangent, at the first key, to the end of the
+  /// previous segment, whose derivative gives the direction the arc leaves the key in: the curve has no corner at that
+  /// key, whatever the interpolation mode of the previous segment, and consecutive circular arc segments whose keys lie
+  /// on a same circle trace that circle. Without such a derivative – on the first segment, or after e.g. a step –, the
+  /// circle passes through the previous key instead, or through the next key on the first segment. The arc is swept
+  /// with a constant angular velocity. If the second key lies on the line going through the first one in the direction
+  /// of the tangent – or if the spline only has two keys –, the segment is a straight line.
+  ///
+  /// Only vector implementors of [`Interpolate`] actually trace arcs; other types, such as scalars, fall back to a
+  /// linear interpolation. Values that cannot be differentiated, such as points and integer vectors, always use the
+  /// circle passing through the previous key, or through the next key on the first segment.
+  ///
+  /// [`Interpolate`]: crate::interpolate::Interpolate
+  CircularArc,
+
   /// Bézier interpolation.
   ///
   /// A control point that uses such an interpolation is associated with an extra point. The segmant
@@ -62,6 +163,19 @@ pub enum Interpolation<T, V> {
   ///
   /// Stroke Bézier interpolation is always a cubic Bézier interpolation by default.
   StrokeBezier(V, V),
+
+  /// Cubic Hermite interpolation, using explicit tangents instead of estimating them from the neighboring keys.
+  ///
+  /// Tangents are derivatives of the value with respect to the sampling parameter. The segment starting at this key
+  /// leaves it with `out_tangent` and reaches the next key with the `in_tangent` of that key if it also uses this
+  /// interpolation mode, or with `out_tangent` otherwise. Setting `in_tangent` and `out_tangent` to different values
+  /// creates a corner at the key.
+  Hermite {
+    /// Tangent of the curve when reaching the key.
+    in_tangent: V,
+    /// Tangent of the curve when leaving the key.
+    out_tangent: V,
+  },
 }
 
 impl<T, V> Default for Interpolation<T, V> {
@@ -70,3 +184,38 @@ impl<T, V> Default for Interpolation<T, V> {
     Interpolation::Linear
   }
 }
+
+/// Behavior of [`Interpolation::CatmullRom`] at the ends of a spline, where a neighbor is missing to estimate the
+/// tangent of the end key.
+///
+/// [`Spline::sample`] cannot sample the first and last Catmull-Rom segments; use [`Spline::sample_with_endpoints`]
+/// to pick how to sample them instead.
+///
+/// [`Spline::sample`]: crate::spline::Spline::sample
+/// [`Spline::sample_with_endpoints`]: crate::spline::Spline::sample_with_endpoints
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+  any(feature = "serialization", feature = "serde"),
+  derive(Deserialize, Serialize),
+  serde(rename_all = "snake_case")
+)]
+pub enum CatmullRomEndpoints {
+  /// The missing neighbor is the end key itself, so that the tangent of the end key is the slope of the end segment.
+  ///
+  /// This is the same behavior as the other modes requiring neighbors, such as [`Interpolation::TCB`].
+  #[default]
+  Clamped,
+
+  /// The tangent of the end key is picked so that the curve has no curvature there, as with natural cubic splines.
+  ///
+  /// The end segment then follows the tangent of its inner key more loosely, instead of bending towards the slope of
+  /// the segment.
+  Natural,
+
+  /// The keys wrap around, as for a closed loop: the first key is preceded by the key before the last one, and the
+  /// last key is followed by the key after the first one.
+  ///
+  /// The first and last keys are expected to hold the same value, in which case the loop is C1-continuous at the
+  /// seam.
+  Cyclic,
+}
@@ -1,44 +1,145 @@
 //! Spline [`Iterator`], in a nutshell.
 //!
 //! You can iterate over a [`Spline<K, V>`]’s keys with the [`IntoIterator`] trait on
-//! `&Spline<K, V>`. This gives you iterated [`Key<K, V>`] keys.
+//! `&Spline<K, V>`. This gives you iterated [`Key<K, V>`] keys. The [`IntoIterator`] trait is also
+//! implemented on `Spline<K, V>`, moving the keys out of the spline.
+//!
+//! Segments – i.e. pairs of adjacent keys – can be iterated over with [`Spline::segments`].
 //!
 //! [`Spline<K, V>`]: crate::spline::Spline
 //! [`Key<K, V>`]: crate::key::Key
 
 use crate::{Key, Spline};
+use core::slice;
+use heapless::Vec;
 
 /// Iterator over spline keys.
 ///
-/// This iterator type is guaranteed to iterate over sorted keys.
-pub struct Iter<'a, T, V, const SIZE: usize>
+/// This iterator type is guaranteed to iterate over sorted keys, and knows how many keys are left. It can also iterate
+/// backwards, from the last key to the first one.
+pub struct Iter<'a, T, V, const SIZE: usize, M = ()>
 where
   T: 'a,
   V: 'a,
+  M: 'a,
 {
-  spline: &'a Spline<T, V, SIZE>,
+  spline: &'a Spline<T, V, SIZE, M>,
   i: usize,
+  // index past the last key left to iterate over
+  end: usize,
 }
 
-impl<'a, T, V, const SIZE: usize> Iterator for Iter<'a, T, V, SIZE> {
-  type Item = &'a Key<T, V>;
+impl<'a, T, V, const SIZE: usize, M> Iterator for Iter<'a, T, V, SIZE, M> {
+  type Item = &'a Key<T, V, M>;
 
   fn next(&mut self) -> Option<Self::Item> {
+    if self.i == self.end {
+      return None;
+    }
+
     let r = self.spline.0.get(self.i);
 
-    if let Some(_) = r {
+    if r.is_some() {
       self.i += 1;
     }
 
     r
   }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.end - self.i;
+    (len, Some(len))
+  }
+}
+
+impl<'a, T, V, const SIZE: usize, M> ExactSizeIterator for Iter<'a, T, V, SIZE, M> {}
+
+impl<'a, T, V, const SIZE: usize, M> DoubleEndedIterator for Iter<'a, T, V, SIZE, M> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.i == self.end {
+      return None;
+    }
+
+    self.end -= 1;
+    self.spline.0.get(self.end)
+  }
 }
 
-impl<'a, T, V, const SIZE: usize> IntoIterator for &'a Spline<T, V, SIZE> {
-  type Item = &'a Key<T, V>;
-  type IntoIter = Iter<'a, T, V, SIZE>;
+impl<'a, T, V, const SIZE: usize, M> IntoIterator for &'a Spline<T, V, SIZE, M> {
+  type Item = &'a Key<T, V, M>;
+  type IntoIter = Iter<'a, T, V, SIZE, M>;
 
   fn into_iter(self) -> Self::IntoIter {
-    Iter { spline: self, i: 0 }
+    Iter {
+      spline: self,
+      i: 0,
+      end: self.0.len(),
+    }
+  }
+}
+
+/// Owning iterator over spline keys.
+///
+/// This iterator type is guaranteed to iterate over sorted keys, and knows how many keys are left.
+pub struct IntoIter<T, V, const SIZE: usize, M = ()> {
+  keys: <Vec<Key<T, V, M>, SIZE> as IntoIterator>::IntoIter,
+  // number of keys left to iterate over
+  len: usize,
+}
+
+impl<T, V, const SIZE: usize, M> Iterator for IntoIter<T, V, SIZE, M> {
+  type Item = Key<T, V, M>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let key = self.keys.next()?;
+    self.len -= 1;
+    Some(key)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.len, Some(self.len))
+  }
+}
+
+impl<T, V, const SIZE: usize, M> ExactSizeIterator for IntoIter<T, V, SIZE, M> {}
+
+impl<T, V, const SIZE: usize, M> IntoIterator for Spline<T, V, SIZE, M> {
+  type Item = Key<T, V, M>;
+  type IntoIter = IntoIter<T, V, SIZE, M>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIter {
+      len: self.0.len(),
+      keys: self.0.into_iter(),
+    }
+  }
+}
+
+/// Iterator over spline segments.
+///
+/// Each segment is yielded as a pair of its lower and upper keys, the lower key holding the interpolation mode of the
+/// segment. This iterator type is guaranteed to iterate over sorted segments.
+pub struct Segments<'a, T, V, M = ()>
+where
+  T: 'a,
+  V: 'a,
+  M: 'a,
+{
+  keys: slice::Windows<'a, Key<T, V, M>>,
+}
+
+impl<'a, T, V, M> Segments<'a, T, V, M> {
+  pub(crate) fn new(keys: &'a [Key<T, V, M>]) -> Self {
+    Segments {
+      keys: keys.windows(2),
+    }
+  }
+}
+
+impl<'a, T, V, M> Iterator for Segments<'a, T, V, M> {
+  type Item = (&'a Key<T, V, M>, &'a Key<T, V, M>);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.keys.next().map(|pair| (&pair[0], &pair[1]))
   }
 }
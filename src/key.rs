@@ -16,29 +16,59 @@ use serde::{Deserialize, Serialize};
 /// interpolation mode used to determine how to interpolate values on the segment defined by this
 /// key and the next one – if existing. Have a look at [`Interpolation`] for further details.
 ///
+/// Keys can be hashed if both their sampling parameter and value types can, e.g. with integers or fixed-point
+/// numbers; floating-point numbers don’t implement [`Hash`](core::hash::Hash).
+///
+/// Keys can also carry user data – e.g. an event name or an easing hint – with a tag of type `M`, which defaults to `()`.
+/// The tag doesn’t take part in interpolation, but rides along with its key: see [`Key::with_tag`]. Struct literals
+/// must name it too, so prefer [`Key::new`] to build keys without user data.
+///
 /// [`Interpolation`]: crate::interpolation::Interpolation
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(
   any(feature = "serialization", feature = "serde"),
   derive(Deserialize, Serialize),
   serde(rename_all = "snake_case")
 )]
-pub struct Key<T, V> {
+#[cfg_attr(feature = "bevy", derive(bevy_reflect::Reflect))]
+pub struct Key<T, V, M = ()> {
   /// Interpolation parameter at which the [`Key`] should be reached.
   pub t: T,
   /// Carried value.
   pub value: V,
   /// Interpolation mode.
   pub interpolation: Interpolation<T, V>,
+  /// User data.
+  ///
+  /// Zero-sized tags, such as the default `()` one, are not serialized.
+  #[cfg_attr(
+    any(feature = "serialization", feature = "serde"),
+    serde(default, skip_serializing_if = "is_zero_sized")
+  )]
+  pub tag: M,
 }
 
 impl<T, V> Key<T, V> {
   /// Create a new key.
   pub fn new(t: T, value: V, interpolation: Interpolation<T, V>) -> Self {
+    Key::with_tag(t, value, interpolation, ())
+  }
+}
+
+impl<T, V, M> Key<T, V, M> {
+  /// Create a new key carrying user data.
+  pub fn with_tag(t: T, value: V, interpolation: Interpolation<T, V>, tag: M) -> Self {
     Key {
       t,
       value,
       interpolation,
+      tag,
     }
   }
 }
+
+// Whether a tag is zero-sized, and thus doesn’t need to be serialized.
+#[cfg(any(feature = "serialization", feature = "serde"))]
+fn is_zero_sized<M>(_: &M) -> bool {
+  core::mem::size_of::<M>() == 0
+}
@@ -18,11 +18,12 @@
 //! # Quickly create splines
 //!
 //! ```
+//! use heapless::Vec;
 //! use splines::{Interpolation, Key, Spline};
 //!
 //! let start = Key::new(0., 0., Interpolation::Linear);
 //! let end = Key::new(1., 10., Interpolation::default());
-//! let spline = Spline::from_vec(vec![start, end]);
+//! let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 //! ```
 //!
 //! You will notice that we used `Interpolation::Linear` for the first key. The first key `start`’s
@@ -40,10 +41,11 @@
 //! If you try to sample in out-of-bounds sampling parameter, you’ll get no value.
 //!
 //! ```
+//! # use heapless::Vec;
 //! # use splines::{Interpolation, Key, Spline};
 //! # let start = Key::new(0., 0., Interpolation::Linear);
 //! # let end = Key::new(1., 10., Interpolation::Linear);
-//! # let spline = Spline::from_vec(vec![start, end]);
+//! # let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 //! assert_eq!(spline.sample(0.), Some(0.));
 //! assert_eq!(spline.clamped_sample(1.), Some(10.));
 //! assert_eq!(spline.sample(1.1), None);
@@ -54,10 +56,11 @@
 //! that purpose.
 //!
 //! ```
+//! # use heapless::Vec;
 //! # use splines::{Interpolation, Key, Spline};
 //! # let start = Key::new(0., 0., Interpolation::Linear);
 //! # let end = Key::new(1., 10., Interpolation::Linear);
-//! # let spline = Spline::from_vec(vec![start, end]);
+//! # let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 //! assert_eq!(spline.clamped_sample(-0.9), Some(0.)); // clamped to the first key
 //! assert_eq!(spline.clamped_sample(1.1), Some(10.)); // clamped to the last key
 //! ```
@@ -87,6 +90,8 @@
 //!   - **Serde.**
 //!     - This feature implements both the `Serialize` and `Deserialize` traits from `serde` for all
 //!       types exported by this crate.
+//!     - Also adds `compact::CompactSpline`, serializing splines as parallel sequences of sampling
+//!       parameters, values and interpolation modes.
 //!     - Enable with the `"serde"` feature.
 //!   - **[cgmath](https://crates.io/crates/cgmath) implementors.**
 //!     - Adds some useful implementations of `Interpolate` for some cgmath types.
@@ -97,6 +102,34 @@
 //!   - **[nalgebra](https://crates.io/crates/nalgebra) implementors.**
 //!     - Adds some useful implementations of `Interpolate` for some nalgebra types.
 //!     - Enable with the `"nalgebra"` feature.
+//!   - **[mint](https://crates.io/crates/mint) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for some mint types.
+//!     - Enable with the `"mint"` feature.
+//!   - **[euclid](https://crates.io/crates/euclid) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for some euclid types, keeping their units.
+//!     - Enable with the `"euclid"` feature.
+//!   - **[palette](https://crates.io/crates/palette) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for some palette color spaces.
+//!     - Enable with the `"palette"` feature.
+//!   - **[num-complex](https://crates.io/crates/num-complex) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for complex numbers.
+//!     - Enable with the `"num-complex"` feature.
+//!   - **[fixed](https://crates.io/crates/fixed) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for some fixed-point number types.
+//!     - Enable with the `"fixed"` feature.
+//!   - **[ultraviolet](https://crates.io/crates/ultraviolet) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for some ultraviolet types.
+//!     - Enable with the `"ultraviolet"` feature.
+//!   - **[vek](https://crates.io/crates/vek) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for some vek types.
+//!     - Enable with the `"vek"` feature.
+//!   - **[half](https://crates.io/crates/half) implementors.**
+//!     - Adds some useful implementations of `Interpolate` for half-precision floating-point numbers.
+//!     - Enable with the `"half"` feature.
+//!   - **[bevy](https://crates.io/crates/bevy) reflection.**
+//!     - Implements `Reflect` from `bevy_reflect` for keys, interpolation modes and splines, so that they can be
+//!       used in bevy components and assets.
+//!     - Enable with the `"bevy"` feature.
 //!   - **Standard library / no standard library.**
 //!     - It’s possible to compile against the standard library or go on your own without it.
 //!     - Compiling with the standard library is enabled by default.
@@ -118,17 +151,50 @@
   )
 )]
 
+mod array;
+#[cfg(feature = "bevy")]
+mod bevy;
 #[cfg(any(feature = "impl-cgmath", feature = "cgmath"))]
 mod cgmath;
+#[cfg(any(feature = "serialization", feature = "serde"))]
+pub mod compact;
+mod duration;
+#[cfg(feature = "euclid")]
+mod euclid;
+#[cfg(feature = "fixed")]
+mod fixed;
 #[cfg(any(feature = "impl-glam", feature = "glam"))]
 mod glam;
+#[cfg(feature = "half")]
+mod half;
 pub mod interpolate;
 pub mod interpolation;
 pub mod iter;
 pub mod key;
+#[cfg(feature = "mint")]
+mod mint;
 #[cfg(any(feature = "impl-nalgebra", feature = "nalgebra"))]
 mod nalgebra;
+#[cfg(feature = "num-complex")]
+mod num_complex;
+#[cfg(feature = "palette")]
+mod palette;
+#[cfg(any(
+  feature = "impl-cgmath",
+  feature = "cgmath",
+  feature = "impl-glam",
+  feature = "glam",
+  feature = "mint",
+  feature = "ultraviolet",
+  feature = "vek"
+))]
+mod quaternion;
 pub mod spline;
+mod tuple;
+#[cfg(feature = "ultraviolet")]
+mod ultraviolet;
+#[cfg(feature = "vek")]
+mod vek;
 
 pub use crate::interpolate::Interpolate;
 pub use crate::interpolation::Interpolation;
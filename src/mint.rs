@@ -0,0 +1,196 @@
+//! [mint] implementors.
+//!
+//! mint types don’t implement any arithmetic operation, so they cannot use [`impl_Interpolate!`]: vectors are
+//! interpolated component-wise with the scalar implementations instead, which yields the same result.
+//!
+//! Quaternions are interpolated with spherical linear interpolation (slerp) for [`Interpolation::Linear`],
+//! [`Interpolation::Cosine`], [`Interpolation::Smoothstep`] and [`Interpolation::Smootherstep`], and with spherical
+//! cubic interpolation (squad) for [`Interpolation::CatmullRom`]. Other interpolation modes are computed
+//! component-wise and normalized afterwards.
+//!
+//! [mint]: https://crates.io/crates/mint
+//! [`impl_Interpolate!`]: crate::impl_Interpolate
+//! [`Interpolation::Linear`]: crate::interpolation::Interpolation::Linear
+//! [`Interpolation::Cosine`]: crate::interpolation::Interpolation::Cosine
+//! [`Interpolation::Smoothstep`]: crate::interpolation::Interpolation::Smoothstep
+//! [`Interpolation::Smootherstep`]: crate::interpolation::Interpolation::Smootherstep
+//! [`Interpolation::CatmullRom`]: crate::interpolation::Interpolation::CatmullRom
+
+use crate::interpolate::{Interpolate, MinMax, Norm};
+use crate::quaternion::impl_Interpolate_quaternion;
+use mint::{Quaternion, Vector2, Vector3, Vector4};
+use num_traits::Float;
+
+// Apply a scalar function to every component of several values.
+fn componentwise<T, const N: usize, const M: usize>(
+  values: [[T; N]; M],
+  f: impl Fn([T; M]) -> T,
+) -> [T; N]
+where
+  T: Copy,
+{
+  core::array::from_fn(|i| f(values.map(|value| value[i])))
+}
+
+macro_rules! impl_Interpolate_componentwise {
+  ($t:ty, $v:ty) => {
+    impl Interpolate<$t> for $v {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a.into(), b.into()], |[a, b]| <$t>::lerp(t, a, b)).into()
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a.into(), b.into()], |[a, b]| <$t>::cosine(t, a, b)).into()
+      }
+
+      fn smoothstep(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a.into(), b.into()], |[a, b]| <$t>::smoothstep(t, a, b)).into()
+      }
+
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        componentwise([a.into(), b.into()], |[a, b]| <$t>::smootherstep(t, a, b)).into()
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        let values = [x.1.into(), a.1.into(), b.1.into(), y.1.into()];
+        componentwise(values, |[xv, av, bv, yv]| {
+          <$t>::cubic_hermite(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+        .into()
+      }
+
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        let values = [a.1.into(), b.1.into(), m0.into(), m1.into()];
+        componentwise(values, |[av, bv, m0, m1]| {
+          <$t>::hermite(t, (a.0, av), (b.0, bv), m0, m1)
+        })
+        .into()
+      }
+
+      fn quintic_hermite(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Self {
+        let values = [x.1.into(), a.1.into(), b.1.into(), y.1.into()];
+        componentwise(values, |[xv, av, bv, yv]| {
+          <$t>::quintic_hermite(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+        .into()
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        let values = [x.1.into(), a.1.into(), b.1.into(), y.1.into()];
+        componentwise(values, |[xv, av, bv, yv]| {
+          <$t>::kochanek_bartels(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv), tcb_a, tcb_b)
+        })
+        .into()
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        componentwise([a.into(), u.into(), b.into()], |[a, u, b]| {
+          <$t>::quadratic_bezier(t, a, u, b)
+        })
+        .into()
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        componentwise([a.into(), u.into(), v.into(), b.into()], |[a, u, v, b]| {
+          <$t>::cubic_bezier(t, a, u, v, b)
+        })
+        .into()
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        componentwise([a.into(), u.into(), v.into(), b.into()], |[a, u, v, b]| {
+          <$t>::cubic_bezier_mirrored(t, a, u, v, b)
+        })
+        .into()
+      }
+    }
+  };
+}
+
+impl_Interpolate_componentwise!(f32, Vector2<f32>);
+impl_Interpolate_componentwise!(f32, Vector3<f32>);
+impl_Interpolate_componentwise!(f32, Vector4<f32>);
+
+impl_Interpolate_componentwise!(f64, Vector2<f64>);
+impl_Interpolate_componentwise!(f64, Vector3<f64>);
+impl_Interpolate_componentwise!(f64, Vector4<f64>);
+
+impl_Interpolate_quaternion!(
+  f32,
+  Quaternion<f32>,
+  Vector4<f32>,
+  <[f32; 4]>::from,
+  Quaternion::from
+);
+impl_Interpolate_quaternion!(
+  f64,
+  Quaternion<f64>,
+  Vector4<f64>,
+  <[f64; 4]>::from,
+  Quaternion::from
+);
+
+macro_rules! impl_Norm {
+  ($t:ty, $v:ty, $n:expr) => {
+    impl Norm<$t> for $v {
+      fn norm(self) -> $t {
+        let components: [$t; $n] = self.into();
+        Float::sqrt(components.iter().map(|c| c * c).sum::<$t>())
+      }
+    }
+  };
+}
+
+impl_Norm!(f32, Vector2<f32>, 2);
+impl_Norm!(f32, Vector3<f32>, 3);
+impl_Norm!(f32, Vector4<f32>, 4);
+impl_Norm!(f32, Quaternion<f32>, 4);
+
+impl_Norm!(f64, Vector2<f64>, 2);
+impl_Norm!(f64, Vector3<f64>, 3);
+impl_Norm!(f64, Vector4<f64>, 4);
+impl_Norm!(f64, Quaternion<f64>, 4);
+
+macro_rules! impl_MinMax {
+  ($v:ty) => {
+    impl MinMax for $v {
+      fn min(self, other: Self) -> Self {
+        componentwise([self.into(), other.into()], |[a, b]| Float::min(a, b)).into()
+      }
+
+      fn max(self, other: Self) -> Self {
+        componentwise([self.into(), other.into()], |[a, b]| Float::max(a, b)).into()
+      }
+    }
+  };
+}
+
+impl_MinMax!(Vector2<f32>);
+impl_MinMax!(Vector3<f32>);
+impl_MinMax!(Vector4<f32>);
+
+impl_MinMax!(Vector2<f64>);
+impl_MinMax!(Vector3<f64>);
+impl_MinMax!(Vector4<f64>);
@@ -1,19 +1,104 @@
 use crate::impl_Interpolate;
+use crate::interpolate::{impl_circular_arc, MinMax, Norm};
 use nalgebra::{Quaternion, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6};
 use num_traits::Float;
 
 impl_Interpolate!(f32, Vector1<f32>, core::f32::consts::PI);
-impl_Interpolate!(f32, Vector2<f32>, core::f32::consts::PI);
-impl_Interpolate!(f32, Vector3<f32>, core::f32::consts::PI);
-impl_Interpolate!(f32, Vector4<f32>, core::f32::consts::PI);
-impl_Interpolate!(f32, Vector5<f32>, core::f32::consts::PI);
-impl_Interpolate!(f32, Vector6<f32>, core::f32::consts::PI);
+impl_Interpolate!(f32, Vector2<f32>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vector3<f32>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vector4<f32>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vector5<f32>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vector6<f32>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
 impl_Interpolate!(f32, Quaternion<f32>, core::f32::consts::PI);
 
 impl_Interpolate!(f64, Vector1<f64>, core::f64::consts::PI);
-impl_Interpolate!(f64, Vector2<f64>, core::f64::consts::PI);
-impl_Interpolate!(f64, Vector3<f64>, core::f64::consts::PI);
-impl_Interpolate!(f64, Vector4<f64>, core::f64::consts::PI);
-impl_Interpolate!(f64, Vector5<f64>, core::f64::consts::PI);
-impl_Interpolate!(f64, Vector6<f64>, core::f64::consts::PI);
+impl_Interpolate!(f64, Vector2<f64>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, Vector3<f64>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, Vector4<f64>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, Vector5<f64>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+impl_Interpolate!(f64, Vector6<f64>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
 impl_Interpolate!(f64, Quaternion<f64>, core::f64::consts::PI);
+
+macro_rules! impl_Norm {
+  ($t:ty, $v:ty) => {
+    impl Norm<$t> for $v {
+      fn norm(self) -> $t {
+        Float::sqrt(self.dot(&self))
+      }
+    }
+  };
+}
+
+impl_Norm!(f32, Vector1<f32>);
+impl_Norm!(f32, Vector2<f32>);
+impl_Norm!(f32, Vector3<f32>);
+impl_Norm!(f32, Vector4<f32>);
+impl_Norm!(f32, Vector5<f32>);
+impl_Norm!(f32, Vector6<f32>);
+
+impl Norm<f32> for Quaternion<f32> {
+  fn norm(self) -> f32 {
+    Float::sqrt(self.coords.dot(&self.coords))
+  }
+}
+
+impl_Norm!(f64, Vector1<f64>);
+impl_Norm!(f64, Vector2<f64>);
+impl_Norm!(f64, Vector3<f64>);
+impl_Norm!(f64, Vector4<f64>);
+impl_Norm!(f64, Vector5<f64>);
+impl_Norm!(f64, Vector6<f64>);
+
+impl Norm<f64> for Quaternion<f64> {
+  fn norm(self) -> f64 {
+    Float::sqrt(self.coords.dot(&self.coords))
+  }
+}
+
+macro_rules! impl_MinMax {
+  ($v:ty) => {
+    impl MinMax for $v {
+      fn min(self, other: Self) -> Self {
+        self.inf(&other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        self.sup(&other)
+      }
+    }
+  };
+}
+
+impl_MinMax!(Vector1<f32>);
+impl_MinMax!(Vector2<f32>);
+impl_MinMax!(Vector3<f32>);
+impl_MinMax!(Vector4<f32>);
+impl_MinMax!(Vector5<f32>);
+impl_MinMax!(Vector6<f32>);
+
+impl_MinMax!(Vector1<f64>);
+impl_MinMax!(Vector2<f64>);
+impl_MinMax!(Vector3<f64>);
+impl_MinMax!(Vector4<f64>);
+impl_MinMax!(Vector5<f64>);
+impl_MinMax!(Vector6<f64>);
@@ -0,0 +1,48 @@
+//! [num-complex] implementors.
+//!
+//! Complex numbers are interpolated as 2D vectors, i.e. their real and imaginary parts are interpolated
+//! independently.
+//!
+//! [num-complex]: https://crates.io/crates/num-complex
+
+use crate::impl_Interpolate;
+use crate::interpolate::{impl_circular_arc, MinMax, Norm};
+use num_complex::Complex;
+use num_traits::Float;
+
+impl_Interpolate!(f32, Complex<f32>, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f64, Complex<f64>, core::f64::consts::PI, {
+  impl_circular_arc!(f64);
+});
+
+macro_rules! impl_Norm {
+  ($t:ty) => {
+    impl Norm<$t> for Complex<$t> {
+      fn norm(self) -> $t {
+        Complex::norm(self)
+      }
+    }
+  };
+}
+
+impl_Norm!(f32);
+impl_Norm!(f64);
+
+macro_rules! impl_MinMax {
+  ($t:ty) => {
+    impl MinMax for Complex<$t> {
+      fn min(self, other: Self) -> Self {
+        Complex::new(Float::min(self.re, other.re), Float::min(self.im, other.im))
+      }
+
+      fn max(self, other: Self) -> Self {
+        Complex::new(Float::max(self.re, other.re), Float::max(self.im, other.im))
+      }
+    }
+  };
+}
+
+impl_MinMax!(f32);
+impl_MinMax!(f64);
@@ -0,0 +1,213 @@
+//! [palette] implementors.
+//!
+//! Colors are interpolated component-wise in their own color space, which is why perceptual spaces such as CIE
+//! L\*a\*b\* or Oklab should be preferred to sRGB: convert the colors of the keys to one of the supported spaces before
+//! building the spline, and convert the sampled colors back to sRGB when displaying them.
+//!
+//! Hues are angles, so they are interpolated along the shortest arc: going from 350° to 10° goes through 0° rather
+//! than through 180°.
+//!
+//! [palette]: https://crates.io/crates/palette
+
+use crate::interpolate::Interpolate;
+use num_traits::Float;
+use palette::{Laba, Lcha, Oklab, Oklch};
+
+// Apply a scalar function to every component of several colors.
+fn componentwise<T, const N: usize, const M: usize>(
+  values: [[T; N]; M],
+  f: impl Fn([T; M]) -> T,
+) -> [T; N]
+where
+  T: Copy,
+{
+  core::array::from_fn(|i| f(values.map(|value| value[i])))
+}
+
+// Unwrap the hues of consecutive colors, so that each hue is at most 180° away from the previous one.
+fn unwrap_hues<T, const N: usize, const M: usize>(values: &mut [[T; N]; M], hue: usize)
+where
+  T: Float,
+{
+  let turn = T::from(360.).unwrap();
+
+  for i in 1..M {
+    let previous = values[i - 1][hue];
+    let delta = values[i][hue] - previous;
+    values[i][hue] = previous + delta - turn * (delta / turn).round();
+  }
+}
+
+// Interpolate colors component-wise with `f`, once converted to arrays with `to`.
+fn interpolate<C, T, const N: usize, const M: usize>(
+  to: impl Fn(C) -> [T; N],
+  from: impl Fn([T; N]) -> C,
+  hue: Option<usize>,
+  colors: [C; M],
+  f: impl Fn([T; M]) -> T,
+) -> C
+where
+  T: Float,
+{
+  let mut values = colors.map(to);
+
+  if let Some(hue) = hue {
+    unwrap_hues(&mut values, hue);
+  }
+
+  from(componentwise(values, f))
+}
+
+macro_rules! impl_Interpolate_color {
+  (<$($g:ident),*> $t:ty, $v:ty, $to:expr, $from:expr, $hue:expr) => {
+    impl<$($g),*> Interpolate<$t> for $v {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, b], |[a, b]| <$t>::lerp(t, a, b))
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, b], |[a, b]| <$t>::cosine(t, a, b))
+      }
+
+      fn smoothstep(t: $t, a: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, b], |[a, b]| <$t>::smoothstep(t, a, b))
+      }
+
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, b], |[a, b]| <$t>::smootherstep(t, a, b))
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        interpolate($to, $from, $hue, [x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::cubic_hermite(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+      }
+
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        // tangents are rates of change rather than colors, so only the hues of the keys are unwrapped
+        let mut keys = [a.1, b.1].map($to);
+
+        if let Some(hue) = $hue {
+          unwrap_hues(&mut keys, hue);
+        }
+
+        let [av, bv] = keys;
+        ($from)(componentwise([av, bv, ($to)(m0), ($to)(m1)], |[av, bv, m0, m1]| {
+          <$t>::hermite(t, (a.0, av), (b.0, bv), m0, m1)
+        }))
+      }
+
+      fn quintic_hermite(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Self {
+        interpolate($to, $from, $hue, [x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::quintic_hermite(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv))
+        })
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        interpolate($to, $from, $hue, [x.1, a.1, b.1, y.1], |[xv, av, bv, yv]| {
+          <$t>::kochanek_bartels(t, (x.0, xv), (a.0, av), (b.0, bv), (y.0, yv), tcb_a, tcb_b)
+        })
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, u, b], |[a, u, b]| <$t>::quadratic_bezier(t, a, u, b))
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, u, v, b], |[a, u, v, b]| <$t>::cubic_bezier(t, a, u, v, b))
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        interpolate($to, $from, $hue, [a, u, v, b], |[a, u, v, b]| {
+          <$t>::cubic_bezier_mirrored(t, a, u, v, b)
+        })
+      }
+    }
+  };
+}
+
+impl_Interpolate_color!(
+  <Wp> f32,
+  Laba<Wp, f32>,
+  |c: Laba<Wp, f32>| [c.color.l, c.color.a, c.color.b, c.alpha],
+  |[l, a, b, alpha]: [f32; 4]| Laba::new(l, a, b, alpha),
+  None
+);
+
+impl_Interpolate_color!(
+  <Wp> f64,
+  Laba<Wp, f64>,
+  |c: Laba<Wp, f64>| [c.color.l, c.color.a, c.color.b, c.alpha],
+  |[l, a, b, alpha]: [f64; 4]| Laba::new(l, a, b, alpha),
+  None
+);
+
+impl_Interpolate_color!(
+  <Wp> f32,
+  Lcha<Wp, f32>,
+  |c: Lcha<Wp, f32>| [c.color.l, c.color.chroma, c.color.hue.into_raw_degrees(), c.alpha],
+  |[l, chroma, hue, alpha]: [f32; 4]| Lcha::new(l, chroma, hue, alpha),
+  Some(2)
+);
+
+impl_Interpolate_color!(
+  <Wp> f64,
+  Lcha<Wp, f64>,
+  |c: Lcha<Wp, f64>| [c.color.l, c.color.chroma, c.color.hue.into_raw_degrees(), c.alpha],
+  |[l, chroma, hue, alpha]: [f64; 4]| Lcha::new(l, chroma, hue, alpha),
+  Some(2)
+);
+
+impl_Interpolate_color!(
+  <> f32,
+  Oklab<f32>,
+  |c: Oklab<f32>| [c.l, c.a, c.b],
+  |[l, a, b]: [f32; 3]| Oklab::new(l, a, b),
+  None
+);
+
+impl_Interpolate_color!(
+  <> f64,
+  Oklab<f64>,
+  |c: Oklab<f64>| [c.l, c.a, c.b],
+  |[l, a, b]: [f64; 3]| Oklab::new(l, a, b),
+  None
+);
+
+impl_Interpolate_color!(
+  <> f32,
+  Oklch<f32>,
+  |c: Oklch<f32>| [c.l, c.chroma, c.hue.into_raw_degrees()],
+  |[l, chroma, hue]: [f32; 3]| Oklch::new(l, chroma, hue),
+  Some(2)
+);
+
+impl_Interpolate_color!(
+  <> f64,
+  Oklch<f64>,
+  |c: Oklch<f64>| [c.l, c.chroma, c.hue.into_raw_degrees()],
+  |[l, chroma, hue]: [f64; 3]| Oklch::new(l, chroma, hue),
+  Some(2)
+);
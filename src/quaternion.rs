@@ -0,0 +1,373 @@
+//! Quaternion helpers shared by the implementors of the supported math crates.
+//!
+//! Quaternions are stored as `[x, y, z, w]` arrays, `w` being the real part.
+
+use num_traits::Float;
+
+/// Normalize a quaternion.
+pub(crate) fn normalize<T>(q: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let norm = q.iter().fold(T::zero(), |acc, &c| acc + c * c).sqrt();
+  q.map(|c| c / norm)
+}
+
+/// Spherical linear interpolation (slerp) of unit quaternions.
+///
+/// The quaternions are interpolated along the shortest great-circle arc, so the result stays normalized and the angular
+/// velocity is constant.
+pub(crate) fn slerp<T>(t: T, a: [T; 4], b: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let mut dot = dot(a, b);
+
+  // take the shortest path
+  let b = if dot < T::zero() {
+    dot = -dot;
+    b.map(|c| -c)
+  } else {
+    b
+  };
+
+  // nearly parallel quaternions are linearly interpolated to prevent dividing by zero
+  if dot > T::one() - T::epsilon().sqrt() {
+    return normalize(core::array::from_fn(|i| a[i] + (b[i] - a[i]) * t));
+  }
+
+  let theta = dot.acos();
+  let sin_theta = theta.sin();
+  let wa = ((T::one() - t) * theta).sin() / sin_theta;
+  let wb = (t * theta).sin() / sin_theta;
+
+  core::array::from_fn(|i| a[i] * wa + b[i] * wb)
+}
+
+/// Derivative of [`slerp`] with respect to `t`.
+pub(crate) fn slerp_derivative<T>(t: T, a: [T; 4], b: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let zero = [T::zero(); 4];
+  slerp_differential((t, T::one()), (a, zero), (b, zero))
+}
+
+// Derivative of slerp(s, p, q) with respect to a parameter which s, p and q depend on, given as pairs of values and
+// derivatives with respect to that parameter.
+fn slerp_differential<T>(
+  (s, ds): (T, T),
+  (p, dp): ([T; 4], [T; 4]),
+  (q, dq): ([T; 4], [T; 4]),
+) -> [T; 4]
+where
+  T: Float,
+{
+  let mut dot = dot(p, q);
+
+  // take the shortest path, like slerp
+  let (q, dq) = if dot < T::zero() {
+    dot = -dot;
+    (q.map(|c| -c), dq.map(|c| -c))
+  } else {
+    (q, dq)
+  };
+
+  // derivative of the normalized linear interpolation: the component of its derivative orthogonal to the sample
+  if dot > T::one() - T::epsilon().sqrt() {
+    let n: [T; 4] = core::array::from_fn(|i| p[i] + (q[i] - p[i]) * s);
+    let dn: [T; 4] = core::array::from_fn(|i| dp[i] + (dq[i] - dp[i]) * s + (q[i] - p[i]) * ds);
+    let norm = self::dot(n, n).sqrt();
+    let n = n.map(|c| c / norm);
+    let projection = self::dot(n, dn);
+
+    return core::array::from_fn(|i| (dn[i] - n[i] * projection) / norm);
+  }
+
+  let theta = dot.acos();
+  let (sin_theta, cos_theta) = (theta.sin(), theta.cos());
+  let dtheta = -(self::dot(dp, q) + self::dot(p, dq)) / sin_theta;
+
+  // weights of slerp and their derivatives
+  let weight = |u: T, du: T| {
+    let angle = u * theta;
+    let dangle = du * theta + u * dtheta;
+    (
+      angle.sin() / sin_theta,
+      (angle.cos() * dangle * sin_theta - angle.sin() * cos_theta * dtheta)
+        / (sin_theta * sin_theta),
+    )
+  };
+  let (wp, dwp) = weight(T::one() - s, -ds);
+  let (wq, dwq) = weight(s, ds);
+
+  core::array::from_fn(|i| dp[i] * wp + p[i] * dwp + dq[i] * wq + q[i] * dwq)
+}
+
+// Dot product of two quaternions.
+fn dot<T>(a: [T; 4], b: [T; 4]) -> T
+where
+  T: Float,
+{
+  (0..4).fold(T::zero(), |acc, i| acc + a[i] * b[i])
+}
+
+// Flip the sign of q if needed, so that it lies in the same hemisphere as reference.
+fn align<T>(q: [T; 4], reference: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  if dot(q, reference) < T::zero() {
+    q.map(|c| -c)
+  } else {
+    q
+  }
+}
+
+// Hamilton product of two quaternions.
+fn mul<T>([ax, ay, az, aw]: [T; 4], [bx, by, bz, bw]: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  [
+    aw * bx + ax * bw + ay * bz - az * by,
+    aw * by - ax * bz + ay * bw + az * bx,
+    aw * bz + ax * by - ay * bx + az * bw,
+    aw * bw - ax * bx - ay * by - az * bz,
+  ]
+}
+
+// Conjugate of a quaternion, which is also its inverse for unit quaternions.
+fn conjugate<T>([x, y, z, w]: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  [-x, -y, -z, w]
+}
+
+// Logarithm of a unit quaternion, which is a pure quaternion.
+fn log<T>([x, y, z, w]: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let sin_theta = (x * x + y * y + z * z).sqrt();
+
+  if sin_theta <= T::epsilon() {
+    return [T::zero(); 4];
+  }
+
+  let k = sin_theta.atan2(w) / sin_theta;
+  [x * k, y * k, z * k, T::zero()]
+}
+
+// Exponential of a pure quaternion, which is a unit quaternion.
+fn exp<T>([x, y, z, _]: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let theta = (x * x + y * y + z * z).sqrt();
+
+  if theta <= T::epsilon() {
+    return normalize([x, y, z, T::one()]);
+  }
+
+  let k = theta.sin() / theta;
+  [x * k, y * k, z * k, theta.cos()]
+}
+
+// Intermediate control quaternion of q, given its previous and next neighbors.
+fn squad_control<T>(previous: [T; 4], q: [T; 4], next: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let four = T::from(4.).unwrap();
+  let inv = conjugate(q);
+  let log_next = log(mul(inv, align(next, q)));
+  let log_previous = log(mul(inv, align(previous, q)));
+
+  mul(
+    q,
+    exp(core::array::from_fn(|i| {
+      -(log_next[i] + log_previous[i]) / four
+    })),
+  )
+}
+
+/// Spherical cubic interpolation (squad) of unit quaternions between `a` and `b`.
+///
+/// `x` and `y` are the neighbors of `a` and `b`, used to compute intermediate control quaternions so that the angular
+/// velocity is continuous across keys, assuming they are evenly spaced. Missing neighbors can be passed as `a` and `b`.
+pub(crate) fn squad<T>(t: T, x: [T; 4], a: [T; 4], b: [T; 4], y: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let two = T::one() + T::one();
+  let b = align(b, a);
+  let sa = squad_control(x, a, b);
+  let sb = squad_control(a, b, y);
+
+  slerp(two * t * (T::one() - t), slerp(t, a, b), slerp(t, sa, sb))
+}
+
+/// Derivative of [`squad`] with respect to `t`.
+pub(crate) fn squad_derivative<T>(t: T, x: [T; 4], a: [T; 4], b: [T; 4], y: [T; 4]) -> [T; 4]
+where
+  T: Float,
+{
+  let two = T::one() + T::one();
+  let b = align(b, a);
+  let sa = squad_control(x, a, b);
+  let sb = squad_control(a, b, y);
+
+  slerp_differential(
+    (two * t * (T::one() - t), two - two * two * t),
+    (slerp(t, a, b), slerp_derivative(t, a, b)),
+    (slerp(t, sa, sb), slerp_derivative(t, sa, sb)),
+  )
+}
+
+// Implement Interpolate for a quaternion type $q, given functions converting quaternions to and from arrays.
+//
+// Linear, cosine and smooth step interpolations use slerp, and Catmull-Rom interpolation uses squad; their derivatives
+// follow them through lerp_derivative and cubic_hermite_derivative. Other modes are computed component-wise on the 4D vector type $v and normalized
+// afterwards; explicit Hermite tangents are then expected to be component-wise derivatives of the quaternions.
+macro_rules! impl_Interpolate_quaternion {
+  ($t:ty, $q:ty, $v:ty, $to:expr, $from:expr) => {
+    impl $crate::interpolate::Interpolate<$t> for $q {
+      fn step(t: $t, threshold: $t, a: Self, b: Self) -> Self {
+        if t < threshold {
+          a
+        } else {
+          b
+        }
+      }
+
+      fn lerp(t: $t, a: Self, b: Self) -> Self {
+        ($from)($crate::quaternion::slerp(t, ($to)(a), ($to)(b)))
+      }
+
+      fn lerp_derivative(t: $t, a: Self, b: Self) -> Option<Self> {
+        Some(($from)($crate::quaternion::slerp_derivative(
+          t,
+          ($to)(a),
+          ($to)(b),
+        )))
+      }
+
+      fn cosine(t: $t, a: Self, b: Self) -> Self {
+        let t = <$t as $crate::interpolate::Interpolate<$t>>::cosine(t, 0., 1.);
+        <Self as $crate::interpolate::Interpolate<$t>>::lerp(t, a, b)
+      }
+
+      fn smoothstep(t: $t, a: Self, b: Self) -> Self {
+        let t = <$t as $crate::interpolate::Interpolate<$t>>::smoothstep(t, 0., 1.);
+        <Self as $crate::interpolate::Interpolate<$t>>::lerp(t, a, b)
+      }
+
+      fn smootherstep(t: $t, a: Self, b: Self) -> Self {
+        let t = <$t as $crate::interpolate::Interpolate<$t>>::smootherstep(t, 0., 1.);
+        <Self as $crate::interpolate::Interpolate<$t>>::lerp(t, a, b)
+      }
+
+      fn cubic_hermite(t: $t, x: ($t, Self), a: ($t, Self), b: ($t, Self), y: ($t, Self)) -> Self {
+        ($from)($crate::quaternion::squad(
+          t,
+          ($to)(x.1),
+          ($to)(a.1),
+          ($to)(b.1),
+          ($to)(y.1),
+        ))
+      }
+
+      fn cubic_hermite_derivative(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Option<Self> {
+        Some(($from)($crate::quaternion::squad_derivative(
+          t,
+          ($to)(x.1),
+          ($to)(a.1),
+          ($to)(b.1),
+          ($to)(y.1),
+        )))
+      }
+
+      fn hermite(t: $t, a: ($t, Self), b: ($t, Self), m0: Self, m1: Self) -> Self {
+        let v = |q: Self| <$v>::from(($to)(q));
+        let q = <$v as $crate::interpolate::Interpolate<$t>>::hermite(
+          t,
+          (a.0, v(a.1)),
+          (b.0, v(b.1)),
+          v(m0),
+          v(m1),
+        );
+        ($from)($crate::quaternion::normalize(q.into()))
+      }
+
+      fn quintic_hermite(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+      ) -> Self {
+        let v = |(t, q): ($t, Self)| (t, <$v>::from(($to)(q)));
+        let q =
+          <$v as $crate::interpolate::Interpolate<$t>>::quintic_hermite(t, v(x), v(a), v(b), v(y));
+        ($from)($crate::quaternion::normalize(q.into()))
+      }
+
+      fn kochanek_bartels(
+        t: $t,
+        x: ($t, Self),
+        a: ($t, Self),
+        b: ($t, Self),
+        y: ($t, Self),
+        tcb_a: ($t, $t, $t),
+        tcb_b: ($t, $t, $t),
+      ) -> Self {
+        let v = |(t, q): ($t, Self)| (t, <$v>::from(($to)(q)));
+        let q = <$v as $crate::interpolate::Interpolate<$t>>::kochanek_bartels(
+          t,
+          v(x),
+          v(a),
+          v(b),
+          v(y),
+          tcb_a,
+          tcb_b,
+        );
+        ($from)($crate::quaternion::normalize(q.into()))
+      }
+
+      fn quadratic_bezier(t: $t, a: Self, u: Self, b: Self) -> Self {
+        let v = |q: Self| <$v>::from(($to)(q));
+        let q = <$v as $crate::interpolate::Interpolate<$t>>::quadratic_bezier(t, v(a), v(u), v(b));
+        ($from)($crate::quaternion::normalize(q.into()))
+      }
+
+      fn cubic_bezier(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        let w = |q: Self| <$v>::from(($to)(q));
+        let q =
+          <$v as $crate::interpolate::Interpolate<$t>>::cubic_bezier(t, w(a), w(u), w(v), w(b));
+        ($from)($crate::quaternion::normalize(q.into()))
+      }
+
+      fn cubic_bezier_mirrored(t: $t, a: Self, u: Self, v: Self, b: Self) -> Self {
+        let w = |q: Self| <$v>::from(($to)(q));
+        let q = <$v as $crate::interpolate::Interpolate<$t>>::cubic_bezier_mirrored(
+          t,
+          w(a),
+          w(u),
+          w(v),
+          w(b),
+        );
+        ($from)($crate::quaternion::normalize(q.into()))
+      }
+    }
+  };
+}
+
+pub(crate) use impl_Interpolate_quaternion;
@@ -1,16 +1,18 @@
 //! Spline curves and operations.
 
 // #[cfg(feature = "std")]
-use crate::interpolate::{Interpolate, Interpolator};
-use crate::interpolation::Interpolation;
+use crate::interpolate::{circle_tangent, dot, Interpolate, Interpolator, Linear, MinMax, Norm};
+use crate::interpolation::{CatmullRomEndpoints, Interpolation};
+use crate::iter::Segments;
 use crate::key::Key;
 #[cfg(not(feature = "std"))]
 use core::cmp::Ordering;
-#[cfg(not(feature = "std"))]
-use core::ops::{Div, Mul};
+use core::fmt;
+use core::ops::{Add, Index};
 use heapless::Vec;
+use num_traits::{Float, FloatConst, NumCast};
 #[cfg(any(feature = "serialization", feature = "serde"))]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 #[cfg(feature = "std")]
 use std::cmp::Ordering;
 
@@ -26,14 +28,18 @@ use std::cmp::Ordering;
 ///     for the required interpolation mode, you get `None`.
 ///   - [`Spline::clamped_sample`]: behaves like [`Spline::sample`] but will return either the first
 ///     or last key if out of bound; it will return `None` if not enough key.
-#[derive(Debug, Clone, Default)]
-#[cfg_attr(
-  any(feature = "serialization", feature = "serde"),
-  derive(Deserialize, Serialize)
-)]
-pub struct Spline<T, V, const SIZE: usize>(pub(crate) Vec<Key<T, V>, SIZE>);
+///   - [`Spline::try_sample`]: behaves like [`Spline::sample`] but returns a [`SampleError`] explaining why
+///     the sampling failed.
+///
+/// Like [`Key`], splines can be hashed if both their sampling parameter and value types can.
+///
+/// Splines of tagged keys – see [`Key::with_tag`] – carry the tag type `M` as their last type parameter. Operations
+/// creating new keys from samples, such as [`Spline::resample`], yield untagged splines.
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(any(feature = "serialization", feature = "serde"), derive(Serialize))]
+pub struct Spline<T, V, const SIZE: usize, M = ()>(pub(crate) Vec<Key<T, V, M>, SIZE>);
 
-impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
+impl<T, V, const SIZE: usize, M> Spline<T, V, SIZE, M> {
   /// Internal sort to ensure invariant of sorting keys is valid.
   fn internal_sort(&mut self)
   where
@@ -46,7 +52,7 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
 
   /// Create a new spline out of keys. The keys don’t have to be sorted even though it’s recommended
   /// to provide ascending sorted ones (for performance purposes).
-  pub fn from_vec(keys: Vec<Key<T, V>, SIZE>) -> Self
+  pub fn from_vec(keys: Vec<Key<T, V, M>, SIZE>) -> Self
   where
     T: PartialOrd,
   {
@@ -55,6 +61,41 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
     spline
   }
 
+  /// Create a new spline out of an array of keys. The keys don’t have to be sorted.
+  ///
+  /// The keys are moved into the spline storage and sorted in place, so this doesn’t allocate and is available
+  /// without `std`.
+  pub fn from_array(keys: [Key<T, V, M>; SIZE]) -> Self
+  where
+    T: PartialOrd,
+  {
+    Self::from_vec(keys.into_iter().collect())
+  }
+
+  /// Create a new spline out of keys given in any order, sorting them by sampling parameter.
+  ///
+  /// Unlike [`Spline::from_vec`], the sort is stable: keys sharing the same sampling parameter keep their relative order.
+  /// Keys whose sampling parameter cannot be compared – e.g. NaN – are placed last, in their original order. Such keys
+  /// cannot be sampled, so they should be filtered out beforehand.
+  pub fn from_keys_unsorted(keys: Vec<Key<T, V, M>, SIZE>) -> Self
+  where
+    T: PartialOrd,
+  {
+    let mut sorted = Vec::new();
+
+    for key in keys {
+      let index = if is_comparable(&key.t) {
+        sorted.partition_point(|k: &Key<T, V, M>| is_comparable(&k.t) && k.t <= key.t)
+      } else {
+        sorted.len()
+      };
+
+      insert_key(&mut sorted, index, key);
+    }
+
+    Spline(sorted)
+  }
+
   /// Clear the spline by removing all keys. Keeps the underlying allocated storage, so adding
   /// new keys should be faster than creating a new [`Spline`]
   #[inline]
@@ -62,26 +103,93 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
     self.0.clear()
   }
 
+  /// Shorten the spline, keeping its first `n` keys and removing the others. Like [`Spline::clear`], this keeps the
+  /// underlying storage.
+  ///
+  /// Nothing happens if the spline has `n` keys or fewer.
+  #[inline]
+  pub fn truncate(&mut self, n: usize) {
+    self.0.truncate(n)
+  }
+
   /// Create a new spline by consuming an `Iterater<Item = Key<T>>`. They keys don’t have to be
   /// sorted.
   ///
   /// # Note on iterators
   ///
   /// It’s valid to use any iterator that implements `Iterator<Item = Key<T>>`. However, you should
-  /// use [`Spline::from_vec`] if you are passing a [`Vec`].
+  /// use [`Spline::from_vec`] if you are passing a [`Vec`]. This is the same as collecting the
+  /// iterator into a [`Spline`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the iterator yields more than `SIZE` keys.
+  #[allow(clippy::should_implement_trait)]
   pub fn from_iter<I>(iter: I) -> Self
   where
-    I: Iterator<Item = Key<T, V>>,
+    I: Iterator<Item = Key<T, V, M>>,
     T: PartialOrd,
   {
-    Self::from_vec(iter.collect())
+    iter.collect()
   }
 
   /// Retrieve the keys of a spline.
-  pub fn keys(&self) -> &[Key<T, V>] {
+  pub fn keys(&self) -> &[Key<T, V, M>] {
     &self.0
   }
 
+  /// Iterate over the segments of the spline, as pairs of adjacent keys.
+  ///
+  /// A spline with `n` keys has `n - 1` segments; nothing is yielded if it has fewer than two keys.
+  pub fn segments(&self) -> Segments<'_, T, V, M> {
+    Segments::new(&self.0)
+  }
+
+  /// Iterate over the segments of the spline along with the interpolation mode governing them.
+  ///
+  /// This is the same as [`Spline::segments`], with the interpolation mode of the lower key of each segment yielded
+  /// last, for convenience.
+  #[allow(clippy::type_complexity)]
+  pub fn segments_with_mode(
+    &self,
+  ) -> impl Iterator<Item = (&Key<T, V, M>, &Key<T, V, M>, &Interpolation<T, V>)> + '_ {
+    self
+      .segments()
+      .map(|(cp0, cp1)| (cp0, cp1, &cp0.interpolation))
+  }
+
+  /// Iterate over all contiguous windows of `n` keys, like [`slice::windows`].
+  ///
+  /// Windows overlap: a spline with `len` keys has `len - n + 1` windows of `n` keys, and none if `n` is greater than
+  /// `len`. This is handy to inspect the neighbourhood of keys, e.g. the four keys a [`Interpolation::CatmullRom`]
+  /// segment depends on.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is `0`.
+  pub fn windows(&self, n: usize) -> impl Iterator<Item = &[Key<T, V, M>]> + '_ {
+    self.0.windows(n)
+  }
+
+  /// Index of the lower key of the segment containing the sampling parameter `t`, or `None` if `t` is out of bounds.
+  ///
+  /// This is the segment a sampling function such as [`Spline::sample`] would interpolate, without sampling it: `t`
+  /// at the last key maps to the last segment, and if several keys share the sampling parameter `t`, the segment
+  /// starting at the last of them is picked. Splines with fewer than two keys have no segments.
+  pub fn segment_at(&self, t: T) -> Option<usize>
+  where
+    T: PartialOrd,
+  {
+    search_lower_cp(&self.0, t)
+  }
+
+  /// Iterate over mutable references to the values carried by the keys.
+  ///
+  /// Sampling parameters cannot be changed this way, as it could break the ordering of the keys.
+  pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+    self.0.iter_mut().map(|key| &mut key.value)
+  }
+
   /// Number of keys.
   #[inline(always)]
   pub fn len(&self) -> usize {
@@ -94,6 +202,38 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
     self.0.is_empty()
   }
 
+  /// Maximum number of keys, i.e. `SIZE`.
+  ///
+  /// Keys are stored inline, so the storage is already allocated for that many keys when the spline is created, and
+  /// adding keys never reallocates it.
+  #[inline(always)]
+  pub const fn capacity(&self) -> usize {
+    SIZE
+  }
+
+  /// Domain of the spline, i.e. the sampling parameters of its first and last keys.
+  ///
+  /// `None` if the spline has no key.
+  pub fn domain(&self) -> Option<(T, T)>
+  where
+    T: Copy,
+  {
+    Some((self.0.first()?.t, self.0.last()?.t))
+  }
+
+  /// Check whether `t` lies within the [domain](Spline::domain) of the spline, bounds included.
+  ///
+  /// `false` if the spline has no key. Note that a sampling parameter in the domain might still not be sampled, e.g.
+  /// on the first and last segments of [`Interpolation::CatmullRom`].
+  pub fn contains(&self, t: T) -> bool
+  where
+    T: Copy + PartialOrd,
+  {
+    self
+      .domain()
+      .is_some_and(|(t_min, t_max)| t_min <= t && t <= t_max)
+  }
+
   /// Sample a spline at a given time, returning the interpolated value along with its associated
   /// key.
   ///
@@ -110,15 +250,70 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
   /// you’re near the beginning of the spline or its end, ensure you have enough keys around to make
   /// the sampling.
   pub fn sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let i = search_lower_cp(&self.0, t)?;
+    let value = self.sample_segment(i, t)?;
+    // sampling on the last key reports that key rather than the segment leading to it
+    let key = if t == self.0[i + 1].t { i + 1 } else { i };
+
+    Some(SampledWithKey { value, key })
+  }
+
+  /// Sample a spline at a given time, returning the interpolated value along with the index of the lower key of the
+  /// sampled segment and the normalized position `u` of `t` inside that segment.
+  ///
+  /// `u` goes from `0` at the lower key to `1` at the upper key: it is the parameter interpolation modes are evaluated
+  /// with. Unlike with [`Spline::sample_with_key`], sampling at the last key reports the last segment, with `u = 1`.
+  ///
+  /// # Return
+  ///
+  /// `None` in the same cases as [`Spline::sample`].
+  pub fn sample_with_local(&self, t: T) -> Option<(V, usize, T)>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let i = search_lower_cp(&self.0, t)?;
+    let value = self.sample_segment(i, t)?;
+    let u = t.normalize(self.0[i].t, self.0[i + 1].t);
+
+    Some((value, i, u))
+  }
+
+  /// Sample the segment starting at the key at index `i`, which must not be the last one.
+  fn sample_segment(&self, i: usize, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.sample_segment_as(i, t, self.0[i].interpolation, self.0[i + 1].interpolation)
+  }
+
+  /// Sample the segment starting at the key at index `i`, which must not be the last one, as if its keys used the
+  /// `interpolation` and `next` modes.
+  fn sample_segment_as(
+    &self,
+    i: usize,
+    t: T,
+    interpolation: Interpolation<T, V>,
+    next: Interpolation<T, V>,
+  ) -> Option<V>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
     let keys = &self.0;
-    let i = search_lower_cp(keys, t)?;
     let cp0 = &keys[i];
 
-    let value = match cp0.interpolation {
+    // the domain is closed on the last key, whatever the interpolation mode of the last segment
+    if i + 2 == keys.len() && t == keys[i + 1].t {
+      return Some(keys[i + 1].value);
+    }
+
+    match interpolation {
       Interpolation::Step(threshold) => {
         let cp1 = &keys[i + 1];
         let nt = t.normalize(cp0.t, cp1.t);
@@ -143,6 +338,22 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
         Some(value)
       }
 
+      Interpolation::Smoothstep => {
+        let cp1 = &keys[i + 1];
+        let nt = t.normalize(cp0.t, cp1.t);
+        let value = V::smoothstep(nt, cp0.value, cp1.value);
+
+        Some(value)
+      }
+
+      Interpolation::Smootherstep => {
+        let cp1 = &keys[i + 1];
+        let nt = t.normalize(cp0.t, cp1.t);
+        let value = V::smootherstep(nt, cp0.value, cp1.value);
+
+        Some(value)
+      }
+
       Interpolation::CatmullRom => {
         // We need at least four points for Catmull Rom; ensure we have them, otherwise, return
         // None.
@@ -165,12 +376,113 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
         }
       }
 
+      Interpolation::TCB {
+        tension,
+        continuity,
+        bias,
+      } => {
+        // Missing neighbors are clamped to the keys of the segment.
+        let cp1 = &keys[i + 1];
+        let cpm0 = &keys[i.saturating_sub(1)];
+        let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+        let nt = t.normalize(cp0.t, cp1.t);
+        let tcb_a = (tension, continuity, bias);
+        let tcb_b = match next {
+          Interpolation::TCB {
+            tension,
+            continuity,
+            bias,
+          } => (tension, continuity, bias),
+          _ => tcb_a,
+        };
+        let value = V::kochanek_bartels(
+          nt,
+          (cpm0.t, cpm0.value),
+          (cp0.t, cp0.value),
+          (cp1.t, cp1.value),
+          (cpm1.t, cpm1.value),
+          tcb_a,
+          tcb_b,
+        );
+
+        Some(value)
+      }
+
+      Interpolation::MonotoneCubic => {
+        // Missing neighbors are replaced by the keys of the segment, which yields one-sided differences.
+        let cp1 = &keys[i + 1];
+        let cpm0 = &keys[i.saturating_sub(1)];
+        let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+        let nt = t.normalize(cp0.t, cp1.t);
+        let value = V::monotone_cubic(
+          nt,
+          (cpm0.t, cpm0.value),
+          (cp0.t, cp0.value),
+          (cp1.t, cp1.value),
+          (cpm1.t, cpm1.value),
+        );
+
+        Some(value)
+      }
+
+      Interpolation::Akima => {
+        let value = V::akima(t.normalize(cp0.t, keys[i + 1].t), akima_keys(keys, i));
+        Some(value)
+      }
+
+      Interpolation::Exponential => {
+        let cp1 = &keys[i + 1];
+        let nt = t.normalize(cp0.t, cp1.t);
+        let value = V::exponential(nt, cp0.value, cp1.value);
+
+        Some(value)
+      }
+
+      Interpolation::CircularArc => {
+        let cp1 = &keys[i + 1];
+        let nt = t.normalize(cp0.t, cp1.t);
+        let value = V::circular_arc(nt, keys, i);
+
+        Some(value)
+      }
+
+      Interpolation::QuinticHermite => {
+        // Missing neighbors are clamped to the keys of the segment.
+        let cp1 = &keys[i + 1];
+        let cpm0 = &keys[i.saturating_sub(1)];
+        let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+        let nt = t.normalize(cp0.t, cp1.t);
+        let value = V::quintic_hermite(
+          nt,
+          (cpm0.t, cpm0.value),
+          (cp0.t, cp0.value),
+          (cp1.t, cp1.value),
+          (cpm1.t, cpm1.value),
+        );
+
+        Some(value)
+      }
+
+      Interpolation::Hermite { out_tangent, .. } => {
+        let cp1 = &keys[i + 1];
+        let nt = t.normalize(cp0.t, cp1.t);
+        let in_tangent = hermite_in_tangent(cp1, out_tangent);
+
+        Some(V::hermite(
+          nt,
+          (cp0.t, cp0.value),
+          (cp1.t, cp1.value),
+          out_tangent,
+          in_tangent,
+        ))
+      }
+
       Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => {
         // We need to check the next control point to see whether we want quadratic or cubic Bezier.
         let cp1 = &keys[i + 1];
         let nt = t.normalize(cp0.t, cp1.t);
 
-        let value = match cp1.interpolation {
+        let value = match next {
           Interpolation::Bezier(v) => V::cubic_bezier_mirrored(nt, cp0.value, u, v, cp1.value),
 
           Interpolation::StrokeBezier(v, _) => V::cubic_bezier(nt, cp0.value, u, v, cp1.value),
@@ -180,152 +492,2562 @@ impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
 
         Some(value)
       }
-    };
-
-    value.map(|value| SampledWithKey { value, key: i })
+    }
   }
 
   /// Sample a spline at a given time.
   ///
+  /// This is the same as [`Spline::try_sample`], discarding the reason of a failure.
   pub fn sample(&self, t: T) -> Option<V>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
-    self.sample_with_key(t).map(|sampled| sampled.value)
+    self.try_sample(t).ok()
   }
 
-  /// Sample a spline at a given time with clamping, returning the interpolated value along with its
-  /// associated key.
-  ///
-  /// # Return
-  ///
-  /// If you sample before the first key or after the last one, return the first key or the last
-  /// one, respectively. Otherwise, behave the same way as [`Spline::sample`].
+  /// Sample a spline at a given time, returning why the sampling failed, if it did.
   ///
-  /// # Error
+  /// The domain of a spline spans from the sampling parameter of its first key to the one of its last key, both
+  /// included: sampling at the last key yields its value. Sampling at any other key yields a value from the segment
+  /// starting at that key.
   ///
-  /// This function returns [`None`] if you have no key.
-  pub fn clamped_sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  /// Several keys can share the same sampling parameter to create a discontinuity. Sampling is right-continuous: at
+  /// that parameter, the segment starting at the last of those keys is sampled, and the empty segments between them
+  /// are never interpolated.
+  pub fn try_sample(&self, t: T) -> Result<V, SampleError<T>>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
-    if self.0.is_empty() {
-      return None;
-    }
-
-    self.sample_with_key(t).or_else(move || {
-      let first = self.0.first().unwrap();
+    let (min, max) = self.domain().ok_or(SampleError::Empty)?;
 
-      if t <= first.t {
-        let sampled = SampledWithKey {
-          value: first.value,
-          key: 0,
-        };
-        Some(sampled)
-      } else {
-        let last = self.0.last().unwrap();
+    let i = match search_lower_cp(&self.0, t) {
+      Some(i) => i,
+      None if t < min => return Err(SampleError::BelowDomain { min, max }),
+      None => return Err(SampleError::AboveDomain { min, max }),
+    };
 
-        if t >= last.t {
-          let sampled = SampledWithKey {
-            value: last.value,
-            key: self.0.len() - 1,
-          };
-          Some(sampled)
-        } else {
-          None
-        }
-      }
-    })
+    self
+      .sample_segment(i, t)
+      .ok_or(SampleError::NotEnoughKeys { key: i })
   }
 
-  /// Sample a spline at a given time with clamping.
-  pub fn clamped_sample(&self, t: T) -> Option<V>
+  /// Sample a spline at a given time, with the same interpolation mode on every segment.
+  ///
+  /// The interpolation modes of the keys are ignored: the segment `t` falls in is sampled with `mode` instead, which
+  /// is handy to compare interpolation modes without rebuilding the keys. With [`Interpolation::TCB`], both keys of
+  /// the segment use the parameters of `mode`.
+  ///
+  /// # Return
+  ///
+  /// `None` if [`Spline::sample`] would fail with `mode` on every key, and for [`Interpolation::Bezier`],
+  /// [`Interpolation::StrokeBezier`] and [`Interpolation::Hermite`], whose control points and tangents are specific
+  /// to each key.
+  pub fn sample_with_mode(&self, t: T, mode: Interpolation<T, V>) -> Option<V>
   where
     T: Interpolator,
     V: Interpolate<T>,
   {
-    self.clamped_sample_with_key(t).map(|sampled| sampled.value)
+    if let Interpolation::Bezier(_)
+    | Interpolation::StrokeBezier(..)
+    | Interpolation::Hermite { .. } = mode
+    {
+      return None;
+    }
+
+    let i = search_lower_cp(&self.0, t)?;
+    self.sample_segment_as(i, t, mode, mode)
   }
 
-  /// Add a key into the spline.
-  pub fn add(&mut self, key: Key<T, V>)
+  /// Sample a spline at a given time, picking how [`Interpolation::CatmullRom`] segments are sampled at the ends of
+  /// the spline.
+  ///
+  /// [`Spline::sample`] cannot sample the first and last Catmull-Rom segments, as their end keys miss a neighbor to
+  /// estimate their tangents. `endpoints` decides how those tangents are estimated instead – see
+  /// [`CatmullRomEndpoints`]. Other segments are sampled the same way as with [`Spline::sample`].
+  pub fn sample_with_endpoints(&self, t: T, endpoints: CatmullRomEndpoints) -> Option<V>
   where
-    T: PartialOrd,
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T>,
   {
-    self.0.push(key);
-    self.internal_sort();
-  }
+    let keys = &self.0;
+    let i = search_lower_cp(keys, t)?;
+    let is_end = i == 0 || i + 2 >= keys.len();
 
-  /// Remove a key from the spline.
-  pub fn remove(&mut self, index: usize) -> Option<Key<T, V>> {
-    if index >= self.0.len() {
-      None
-    } else {
-      Some(self.0.remove(index))
+    if !is_end || !matches!(keys[i].interpolation, Interpolation::CatmullRom) {
+      return self.sample_segment(i, t);
+    }
+
+    let (cp0, cp1) = (&keys[i], &keys[i + 1]);
+
+    if i + 2 == keys.len() && t == cp1.t {
+      return Some(cp1.value);
     }
+
+    let two = T::one() + T::one();
+    let slope = (cp1.value - cp0.value) / (cp1.t - cp0.t);
+    let m0 = self.catmull_rom_tangent(i, endpoints);
+    let m1 = self.catmull_rom_tangent(i + 1, endpoints);
+    let (m0, m1) = match (m0, m1, endpoints) {
+      // zero second derivative at the end key
+      (None, Some(m1), CatmullRomEndpoints::Natural) => ((slope * (two + T::one()) - m1) / two, m1),
+      (Some(m0), None, CatmullRomEndpoints::Natural) => (m0, (slope * (two + T::one()) - m0) / two),
+      (m0, m1, _) => (m0.unwrap_or(slope), m1.unwrap_or(slope)),
+    };
+    let nt = t.normalize(cp0.t, cp1.t);
+
+    Some(V::hermite(
+      nt,
+      (cp0.t, cp0.value),
+      (cp1.t, cp1.value),
+      m0,
+      m1,
+    ))
   }
 
-  /// Update a key and return the key already present.
+  /// Sample a spline at a given time, looping over its domain.
   ///
-  /// The key is updated — if present — with the provided function.
+  /// `t` is wrapped into the domain – modulo its length – before sampling, so that sampling past the last key
+  /// continues from the first one, e.g. for cyclic animations. [`Interpolation::CatmullRom`] segments wrap their
+  /// neighbors around the seam, as with [`CatmullRomEndpoints::Cyclic`], so that the loop is C1-continuous there;
+  /// other modes requiring neighbors clamp them at the seam, as with [`Spline::sample`].
   ///
-  /// # Notes
+  /// The first and last keys meet at the seam, so they are expected to hold the same value.
   ///
-  /// That function makes sense only if you want to change the interpolator (i.e. [`Key::t`]) of
-  /// your key. If you just want to change the interpolation mode or the carried value, consider
-  /// using the [`Spline::get_mut`] method instead as it will be way faster.
-  pub fn replace<F>(&mut self, index: usize, f: F) -> Option<Key<T, V>>
+  /// # Return
+  ///
+  /// `None` if the spline has fewer than two keys, if all its keys share the same sampling parameter, or if `t` is NaN
+  /// or infinite.
+  pub fn sample_looped(&self, t: T) -> Option<V>
   where
-    F: FnOnce(&Key<T, V>) -> Key<T, V>,
-    T: PartialOrd,
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T>,
   {
-    let key = self.remove(index)?;
-    self.add(f(&key));
-    Some(key)
-  }
+    let (t_min, t_max) = self.domain()?;
+    let length = t_max - t_min;
 
-  /// Get a key at a given index.
-  pub fn get(&self, index: usize) -> Option<&Key<T, V>> {
-    self.0.get(index)
-  }
+    if length <= T::zero() {
+      return None;
+    }
 
-  /// Mutably get a key at a given index.
-  pub fn get_mut(&mut self, index: usize) -> Option<KeyMut<T, V>> {
-    self.0.get_mut(index).map(|key| KeyMut {
-      value: &mut key.value,
-      interpolation: &mut key.interpolation,
-    })
-  }
-}
+    let offset = (t - t_min) % length;
 
-/// A sampled value along with its key index.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct SampledWithKey<V> {
-  /// Sampled value.
-  pub value: V,
+    // NaN and infinite parameters cannot be wrapped
+    if !offset.is_finite() {
+      return None;
+    }
 
-  /// Key index.
-  pub key: usize,
-}
+    let offset = if offset < T::zero() {
+      offset + length
+    } else {
+      offset
+    };
 
-/// A mutable [`Key`].
-///
-/// Mutable keys allow to edit the carried values and the interpolation mode but not the actual
-/// interpolator value as it would invalidate the internal structure of the [`Spline`]. If you
-/// want to achieve this, you’re advised to use [`Spline::replace`].
-#[derive(Debug)]
-pub struct KeyMut<'a, T, V> {
-  /// Carried value.
-  pub value: &'a mut V,
-  /// Interpolation mode to use for that key.
-  pub interpolation: &'a mut Interpolation<T, V>,
-}
+    // rounding might push the wrapped parameter slightly past the last key
+    self.sample_with_endpoints((t_min + offset).min(t_max), CatmullRomEndpoints::Cyclic)
+  }
+
+  // Catmull-Rom tangent of the key at index `k`, with respect to the sampling parameter.
+  //
+  // `None` if the key misses a neighbor – i.e. it’s an end key and `endpoints` is not cyclic.
+  fn catmull_rom_tangent(&self, k: usize, endpoints: CatmullRomEndpoints) -> Option<V>
+  where
+    T: Float,
+    V: Linear<T>,
+  {
+    let keys = &self.0;
+    let n = keys.len();
+    let cyclic = endpoints == CatmullRomEndpoints::Cyclic;
+
+    // neighbors across the seam are shifted by the length of the domain
+    let previous = if k > 0 {
+      (keys[k - 1].t, keys[k - 1].value)
+    } else if cyclic {
+      let key = &keys[n - 2];
+      (keys[0].t - (keys[n - 1].t - key.t), key.value)
+    } else {
+      return None;
+    };
+
+    let next = if k + 1 < n {
+      (keys[k + 1].t, keys[k + 1].value)
+    } else if cyclic {
+      let key = &keys[1];
+      (keys[n - 1].t + (key.t - keys[0].t), key.value)
+    } else {
+      return None;
+    };
+
+    Some((next.1 - previous.1) / (next.0 - previous.0))
+  }
+
+  /// Sample a spline with a normalized sampling parameter.
+  ///
+  /// `u` is mapped linearly from `[0, 1]` onto the domain of the spline – see [`Spline::domain`] – before sampling
+  /// with [`Spline::sample`]. If all keys share the same sampling parameter, `u` is mapped onto it.
+  pub fn normalized_sample(&self, u: T) -> Option<V>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+  {
+    self.sample(self.denormalize(u)?)
+  }
+
+  /// Sample a spline with a normalized sampling parameter, with clamping.
+  ///
+  /// This is the same as [`Spline::normalized_sample`], sampling with [`Spline::clamped_sample`] instead.
+  pub fn clamped_normalized_sample(&self, u: T) -> Option<V>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+  {
+    self.clamped_sample(self.denormalize(u)?)
+  }
+
+  /// Map a normalized sampling parameter onto the domain of the spline.
+  fn denormalize(&self, u: T) -> Option<T>
+  where
+    T: Float,
+  {
+    let (t_min, t_max) = self.domain()?;
+    Some(t_min + (t_max - t_min) * u)
+  }
+
+  /// Sample a spline at a given time, returning the interpolated value along with its first derivative with
+  /// respect to the sampling parameter.
+  ///
+  /// The derivative is computed analytically from the definition of the interpolation mode of the segment `t` lies
+  /// in. [`Interpolation::Step`] segments have a zero derivative.
+  ///
+  /// # Return
+  ///
+  /// `None` if [`Spline::sample`] returns `None` for the same sampling parameter, or if `t` is the sampling parameter
+  /// of the last key and is shared by the key before it.
+  pub fn sample_with_derivative(&self, t: T) -> Option<(V, V)>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T>,
+  {
+    let (value, derivative, _) = self.sample_with_derivatives(t)?;
+    Some((value, derivative))
+  }
+
+  /// Sample the second derivative – the acceleration – of a spline at a given time, with respect to the sampling
+  /// parameter.
+  ///
+  /// Like the derivative of [`Spline::sample_with_derivative`], it is computed analytically from the definition of the
+  /// interpolation mode of the segment `t` lies in, without building a derivative spline: it is zero on
+  /// [`Interpolation::Step`] and [`Interpolation::Linear`] segments, and varies linearly along cubic segments, such as
+  /// [`Interpolation::CatmullRom`] or [`Interpolation::Bezier`] ones. It is estimated by differentiating the first
+  /// derivative numerically on [`Interpolation::Exponential`] segments.
+  ///
+  /// # Return
+  ///
+  /// `None` if [`Spline::sample_with_derivative`] returns `None` for the same sampling parameter.
+  pub fn sample_second_derivative(&self, t: T) -> Option<V>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T>,
+  {
+    let (_, _, second) = self.sample_with_derivatives(t)?;
+    Some(second)
+  }
+
+  /// Curvature of the spline at a given time.
+  ///
+  /// The curvature is computed from the first and second derivatives of the segment `t` lies in, with
+  /// [`Norm::curvature`]: for vectors, it’s the unsigned curvature `|r' × r''| / |r'|³` of the curve traced by the
+  /// values – the inverse of the radius of the osculating circle –, and for scalars, it’s the signed second
+  /// derivative.
+  ///
+  /// The second derivative is computed analytically, except for [`Interpolation::Exponential`] segments, for which it
+  /// is estimated by differentiating their first derivative numerically.
+  ///
+  /// # Return
+  ///
+  /// `None` if [`Spline::sample`] returns `None` for the same sampling parameter, or if the curvature is undefined
+  /// because the curve doesn’t move at `t` – which is always the case for vectors on [`Interpolation::Step`]
+  /// segments.
+  pub fn curvature(&self, t: T) -> Option<T>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    let (_, first, second) = self.sample_with_derivatives(t)?;
+    V::curvature(first, second)
+  }
+
+  /// Sample a spline at a given time, returning the interpolated value along with its speed – the norm of its
+  /// derivative returned by [`Spline::sample_with_derivative`].
+  ///
+  /// [`Interpolation::Step`] segments have a zero speed.
+  ///
+  /// # Return
+  ///
+  /// `None` if [`Spline::sample_with_derivative`] returns `None` for the same sampling parameter.
+  pub fn sample_with_speed(&self, t: T) -> Option<(V, T)>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    let (value, derivative) = self.sample_with_derivative(t)?;
+    Some((value, derivative.norm()))
+  }
+
+  /// Unit tangent of the spline at a given time.
+  ///
+  /// The tangent is the derivative returned by [`Spline::sample_with_derivative`], divided by its norm: it gives the
+  /// direction in which the curve moves at `t`. For scalars, it’s the sign of the derivative.
+  ///
+  /// # Return
+  ///
+  /// `None` if [`Spline::sample`] returns `None` for the same sampling parameter, or if the derivative is zero – e.g.
+  /// on [`Interpolation::Step`] segments –, as the direction is undefined.
+  pub fn tangent(&self, t: T) -> Option<V>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    let (_, derivative) = self.sample_with_derivative(t)?;
+    let speed = derivative.norm();
+
+    if speed == T::zero() {
+      None
+    } else {
+      Some(derivative / speed)
+    }
+  }
+
+  // Sample a spline at a given time, returning the interpolated value along with its first and second derivatives
+  // with respect to the sampling parameter.
+  fn sample_with_derivatives(&self, t: T) -> Option<(V, V, V)>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T>,
+  {
+    let SampledWithKey { value, key } = self.sample_with_key(t)?;
+    let keys = &self.0;
+    let i = key.min(keys.len() - 2);
+    let (cp0, cp1) = (&keys[i], &keys[i + 1]);
+    let h = cp1.t - cp0.t;
+    let (first, second) = segment_derivatives(keys, i, t.normalize(cp0.t, cp1.t))?;
+
+    Some((value, first / h, second / (h * h)))
+  }
+
+  /// Create a [`SplineCursor`] to sample this spline sequentially.
+  pub fn cursor(&self) -> SplineCursor<'_, T, V, SIZE, M> {
+    SplineCursor {
+      spline: self,
+      segment: 0,
+    }
+  }
+
+  /// Sample the spline at several sampling parameters at once, writing the results to `out`.
+  ///
+  /// `out[i]` is set to the same value as `self.sample(ts[i])`. Sampling parameters don’t have to be sorted, but
+  /// sorting them in ascending order is recommended: the segment of the previous sample is then reused – as with
+  /// [`SplineCursor`] – so that looking segments up runs in amortized constant time instead of *O(log n)*.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `ts` and `out` don’t have the same length.
+  pub fn sample_many(&self, ts: &[T], out: &mut [Option<V>])
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    assert_eq!(
+      ts.len(),
+      out.len(),
+      "sampling parameters and output must have the same length"
+    );
+
+    let mut cursor = self.cursor();
+    for (&t, sampled) in ts.iter().zip(out) {
+      *sampled = cursor.sample(t);
+    }
+  }
+
+  /// Iterate over `n` samples of the spline, evenly spaced across its domain, as `(t, value)` pairs.
+  ///
+  /// The first and last samples are the first and last keys of the spline, so that both ends of the domain are
+  /// included. Nothing is yielded if the spline is empty or if `n` is zero, and only the first key if `n` is one.
+  ///
+  /// Parameters that cannot be sampled, e.g. on the first and last segments of [`Interpolation::CatmullRom`], are
+  /// skipped, so fewer than `n` samples might be yielded. As with [`Spline::sample_many`], the segment of the
+  /// previous sample is reused, so that iterating runs in amortized constant time per sample.
+  pub fn iter_sampled(&self, n: usize) -> impl Iterator<Item = (T, V)> + '_
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+  {
+    let n = if self.is_empty() { 0 } else { n };
+    let mut cursor = self.cursor();
+
+    (0..n).filter_map(move |k| {
+      let (first, last) = (&self.0[0], &self.0[self.0.len() - 1]);
+
+      if k == 0 {
+        Some((first.t, first.value))
+      } else if k == n - 1 {
+        Some((last.t, last.value))
+      } else {
+        let t = first.t + (last.t - first.t) * T::from(k).unwrap() / T::from(n - 1).unwrap();
+        cursor.sample(t).map(|value| (t, value))
+      }
+    })
+  }
+
+  /// Sample a spline at a given time with clamping, returning the interpolated value along with its
+  /// associated key.
+  ///
+  /// # Return
+  ///
+  /// If you sample before the first key or after the last one, return the first key or the last
+  /// one, respectively. Otherwise, behave the same way as [`Spline::sample`].
+  ///
+  /// # Error
+  ///
+  /// This function returns [`None`] if you have no key.
+  pub fn clamped_sample_with_key(&self, t: T) -> Option<SampledWithKey<V>>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    if self.0.is_empty() {
+      return None;
+    }
+
+    self.sample_with_key(t).or_else(move || {
+      let first = self.0.first().unwrap();
+
+      if t <= first.t {
+        let sampled = SampledWithKey {
+          value: first.value,
+          key: 0,
+        };
+        Some(sampled)
+      } else {
+        let last = self.0.last().unwrap();
+
+        if t >= last.t {
+          let sampled = SampledWithKey {
+            value: last.value,
+            key: self.0.len() - 1,
+          };
+          Some(sampled)
+        } else {
+          None
+        }
+      }
+    })
+  }
+
+  /// Sample a spline at a given time with clamping.
+  pub fn clamped_sample(&self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    self.clamped_sample_with_key(t).map(|sampled| sampled.value)
+  }
+
+  /// Sample the spline with clamping at `n` evenly spaced sampling parameters from `start` to `end`, both included,
+  /// collecting the values.
+  ///
+  /// Sampling parameters outside of the domain are clamped – see [`Spline::clamped_sample`] – so that every parameter
+  /// yields a value, which suits filling plots and GPU buffers. Only `start` is sampled if `n` is one. The result is
+  /// empty if the spline is empty; parameters that cannot be sampled even with clamping, e.g. on the first and last
+  /// segments of [`Interpolation::CatmullRom`], are skipped.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is greater than `OUT`.
+  pub fn sample_range<const OUT: usize>(&self, start: T, end: T, n: usize) -> Vec<V, OUT>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+  {
+    assert!(n <= OUT, "samples capacity ({}) exceeded", OUT);
+
+    (0..n)
+      .filter_map(|k| {
+        let t = if k == 0 {
+          start
+        } else if k + 1 == n {
+          end
+        } else {
+          start + (end - start) * T::from(k).unwrap() / T::from(n - 1).unwrap()
+        };
+
+        self.clamped_sample(t)
+      })
+      .collect()
+  }
+
+  /// Add a key into the spline.
+  ///
+  /// The key can be added anywhere: keys are sorted again afterwards. See [`Spline::push`] to cheaply append keys
+  /// that are already in order.
+  ///
+  /// If the spline already holds `SIZE` keys, the key is dropped; use [`Spline::try_add`] to get it back instead.
+  pub fn add(&mut self, key: Key<T, V, M>)
+  where
+    T: PartialOrd,
+  {
+    let _ = self.try_add(key);
+  }
+
+  /// Add a key into the spline, giving it back if the spline is full.
+  ///
+  /// This is the fallible version of [`Spline::add`].
+  ///
+  /// # Return
+  ///
+  /// `Err(key)` if the spline already holds `SIZE` keys, in which case the spline is left untouched.
+  pub fn try_add(&mut self, key: Key<T, V, M>) -> Result<(), Key<T, V, M>>
+  where
+    T: PartialOrd,
+  {
+    self.0.push(key)?;
+    self.internal_sort();
+    Ok(())
+  }
+
+  /// Append a key at the end of the spline.
+  ///
+  /// This is the cheapest way to build a spline from left to right, as keys are neither searched nor sorted: the
+  /// sampling parameter of `key` must be greater than or equal to the one of the last key, which is checked in debug
+  /// builds. Use [`Spline::insert`] or [`Spline::add`] to add keys anywhere else.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the spline already holds `SIZE` keys.
+  pub fn push(&mut self, key: Key<T, V, M>)
+  where
+    T: PartialOrd,
+  {
+    debug_assert!(
+      self.0.last().into_iter().all(|last| last.t <= key.t),
+      "keys must be pushed in order"
+    );
+
+    push_key(&mut self.0, key);
+  }
+
+  /// Insert a key into the spline, at the position that keeps the keys sorted.
+  ///
+  /// Contrary to [`Spline::add`], the keys are not sorted again: the position of the new key is found by binary
+  /// search and the following keys are shifted. If keys with the same sampling parameter already exist, the new key
+  /// is inserted after them.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the spline already holds `SIZE` keys.
+  pub fn insert(&mut self, key: Key<T, V, M>)
+  where
+    T: PartialOrd,
+  {
+    let index = self.0.partition_point(|k| k.t <= key.t);
+    insert_key(&mut self.0, index, key);
+  }
+
+  /// Remove a key from the spline, returning it.
+  ///
+  /// The following keys are shifted, so the keys stay sorted. `None` is returned if `index` is out of bounds.
+  ///
+  /// Removing keys until fewer than two remain is allowed, even though the spline cannot be sampled anymore – see
+  /// [`Spline::sample`].
+  pub fn remove(&mut self, index: usize) -> Option<Key<T, V, M>> {
+    if index >= self.0.len() {
+      None
+    } else {
+      Some(self.0.remove(index))
+    }
+  }
+
+  /// Keep only the keys for which `f` returns `true`, removing the others.
+  ///
+  /// Keys are visited in order and the remaining ones keep their order, so they stay sorted. As with
+  /// [`Spline::remove`], the spline cannot be sampled anymore if fewer than two keys remain.
+  pub fn retain<F>(&mut self, f: F)
+  where
+    F: FnMut(&Key<T, V, M>) -> bool,
+  {
+    self.0.retain(f)
+  }
+
+  /// Update a key and return the key already present.
+  ///
+  /// The key is updated — if present — with the provided function. The new key is moved to the position that keeps the
+  /// keys sorted, so its [`Key::t`] can be changed freely; to overwrite a key, pass a closure ignoring its argument,
+  /// e.g. `spline.replace(index, |_| key)`. `None` is returned if `index` is out of bounds.
+  ///
+  /// # Notes
+  ///
+  /// That function makes sense only if you want to change the interpolator (i.e. [`Key::t`]) of
+  /// your key. If you just want to change the interpolation mode or the carried value, consider
+  /// using the [`Spline::get_mut`] method instead as it will be way faster.
+  pub fn replace<F>(&mut self, index: usize, f: F) -> Option<Key<T, V, M>>
+  where
+    F: FnOnce(&Key<T, V, M>) -> Key<T, V, M>,
+    T: PartialOrd,
+  {
+    let key = self.remove(index)?;
+    self.insert(f(&key));
+    Some(key)
+  }
+
+  /// Get a key at a given index.
+  pub fn get(&self, index: usize) -> Option<&Key<T, V, M>> {
+    self.0.get(index)
+  }
+
+  /// Get the first key, which has the lowest sampling parameter.
+  pub fn first(&self) -> Option<&Key<T, V, M>> {
+    self.0.first()
+  }
+
+  /// Get the last key, which has the highest sampling parameter.
+  pub fn last(&self) -> Option<&Key<T, V, M>> {
+    self.0.last()
+  }
+
+  /// Mutably get a key at a given index.
+  pub fn get_mut(&mut self, index: usize) -> Option<KeyMut<'_, T, V, M>> {
+    self.0.get_mut(index).map(|key| KeyMut {
+      value: &mut key.value,
+      interpolation: &mut key.interpolation,
+      tag: &mut key.tag,
+    })
+  }
+
+  /// Split the spline at a given time into two splines.
+  ///
+  /// The first spline contains the keys before `t` and the second one the keys after it. A key sampled at `t` ends
+  /// both splines, so that they share the value at `t`. If a key already exists at `t`, it is used instead of a sampled
+  /// one.
+  ///
+  /// The sampled key uses the interpolation mode of the segment `t` lies in, so that interpolation modes are preserved
+  /// on both sides. Keep in mind that the split segment is reproduced exactly only for modes that don’t depend on the
+  /// segment bounds (e.g. [`Interpolation::Linear`]), and that modes requiring neighbors, such as
+  /// [`Interpolation::CatmullRom`], lose them at the split. The sampled key carries a default tag.
+  ///
+  /// # Return
+  ///
+  /// `None` if `t` is outside of the spline’s domain or if the spline cannot be sampled at `t`.
+  pub fn split_at(&self, t: T) -> Option<(Self, Self)>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+    M: Clone + Default,
+  {
+    let keys = &self.0;
+    let (first, last) = (keys.first()?, keys.last()?);
+
+    if t < first.t || t > last.t {
+      return None;
+    }
+
+    // index of the first key at or after t
+    let i = keys.partition_point(|key| key.t < t);
+
+    if keys[i].t == t {
+      let left = keys[..=i].iter().cloned().collect();
+      let right = keys[i..].iter().cloned().collect();
+
+      Some((Spline(left), Spline(right)))
+    } else {
+      let value = self.sample(t)?;
+      let key = Key::with_tag(t, value, keys[i - 1].interpolation, M::default());
+      let left = keys[..i]
+        .iter()
+        .cloned()
+        .chain(core::iter::once(key.clone()))
+        .collect();
+      let right = core::iter::once(key)
+        .chain(keys[i..].iter().cloned())
+        .collect();
+
+      Some((Spline(left), Spline(right)))
+    }
+  }
+
+  /// Restrict the domain of the spline to `[start, end]`, creating a new spline.
+  ///
+  /// This is like splitting the spline with [`Spline::split_at`] at `start` and `end`, and keeping the middle spline:
+  /// keys outside of the range are dropped, and keys sampled at `start` and `end` bound the new spline, unless keys
+  /// already exist there. Both boundary keys are sampled from this spline, so they match its values, and use the
+  /// interpolation mode of the segment they lie in, so that interpolation modes are preserved inside the range. The
+  /// same limitations as with [`Spline::split_at`] apply to modes depending on the segment bounds or on neighbors.
+  /// Sampled keys carry a default tag.
+  ///
+  /// # Return
+  ///
+  /// `None` if `start` is greater than `end`, if the range is not contained in the spline’s domain or if the spline
+  /// cannot be sampled at `start` or `end`.
+  pub fn clamp_domain(&self, start: T, end: T) -> Option<Self>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+    M: Clone + Default,
+  {
+    let keys = &self.0;
+    let (first, last) = (keys.first()?, keys.last()?);
+
+    if start > end || start < first.t || end > last.t {
+      return None;
+    }
+
+    // indices of the first keys at or after start and end
+    let i = keys.partition_point(|key| key.t < start);
+    let j = keys.partition_point(|key| key.t < end);
+    let mut clamped = Vec::new();
+
+    if keys[i].t != start {
+      let value = self.sample(start)?;
+      push_key(
+        &mut clamped,
+        Key::with_tag(start, value, keys[i - 1].interpolation, M::default()),
+      );
+    }
+
+    for key in &keys[i..j] {
+      push_key(&mut clamped, key.clone());
+    }
+
+    if keys[j].t == end {
+      push_key(&mut clamped, keys[j].clone());
+    } else if start < end {
+      let value = self.sample(end)?;
+      push_key(
+        &mut clamped,
+        Key::with_tag(end, value, keys[j - 1].interpolation, M::default()),
+      );
+    }
+
+    Some(Spline(clamped))
+  }
+
+  /// Concatenate this spline with another one, creating a new spline.
+  ///
+  /// The keys of `other` are shifted by `offset` and merged with the keys of this spline, so that the resulting spline
+  /// samples as this spline, then as `other`. `offset` is typically the last sampling parameter of this spline, so
+  /// that `other` starts right where this spline ends.
+  ///
+  /// If a key of `other` ends up at the same sampling parameter as a key of this spline, only the key of `other` is
+  /// kept: at the junction, the resulting spline takes the first key of `other`.
+  ///
+  /// The capacity of the resulting spline, `OUT`, is independent of the capacities of the two input splines, so that
+  /// it can hold all the keys.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the resulting spline has more than `OUT` keys.
+  pub fn concat<const OTHER: usize, const OUT: usize>(
+    &self,
+    other: &Spline<T, V, OTHER, M>,
+    offset: T,
+  ) -> Spline<T, V, OUT, M>
+  where
+    T: PartialOrd + Copy + Add<Output = T>,
+    V: Copy,
+    M: Clone,
+  {
+    let mut keys = Vec::new();
+    let mut a = self.0.iter().cloned().peekable();
+    let mut b = other
+      .0
+      .iter()
+      .map(|key| Key {
+        t: key.t + offset,
+        ..key.clone()
+      })
+      .peekable();
+
+    loop {
+      let key = match (a.peek(), b.peek()) {
+        (Some(ka), Some(kb)) if ka.t < kb.t => a.next(),
+        (Some(ka), Some(kb)) if ka.t == kb.t => {
+          a.next();
+          b.next()
+        }
+        (Some(_), Some(_)) | (None, Some(_)) => b.next(),
+        (Some(_), None) => a.next(),
+        (None, None) => break,
+      };
+
+      if let Some(key) = key {
+        push_key(&mut keys, key);
+      }
+    }
+
+    Spline(keys)
+  }
+
+  /// Blend this spline with another one, creating a new spline.
+  ///
+  /// Both splines are sampled with [`Spline::clamped_sample`] at every sampling parameter of their keys, and the new
+  /// spline has an [`Interpolation::Linear`] key at each of them, linearly interpolating the two samples by `weight`:
+  /// `0` yields the value of this spline, `1` the value of `other`. Outside the domain of one of the splines, its
+  /// first or last value is used. Keys shared by both splines – at the same sampling parameter – are blended once.
+  ///
+  /// Like [`Spline::resample`], this bakes the blend down to a piecewise linear approximation, which is exact in
+  /// between keys only if both splines are linear. Parameters that cannot be sampled, e.g. on the first and last
+  /// segments of [`Interpolation::CatmullRom`], are skipped, and the new spline is empty if either spline is.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new spline has more than `OUT` keys.
+  pub fn blend<const OTHER: usize, const OUT: usize>(
+    &self,
+    other: &Spline<T, V, OTHER, M>,
+    weight: T,
+  ) -> Spline<T, V, OUT>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let mut keys = Vec::new();
+    let mut a = self.0.iter().map(|key| key.t).peekable();
+    let mut b = other.0.iter().map(|key| key.t).peekable();
+
+    loop {
+      let t = match (a.peek(), b.peek()) {
+        (Some(&ta), Some(&tb)) if ta < tb => a.next(),
+        (Some(&ta), Some(&tb)) if ta == tb => {
+          a.next();
+          b.next()
+        }
+        (Some(_), Some(_)) | (None, Some(_)) => b.next(),
+        (Some(_), None) => a.next(),
+        (None, None) => break,
+      };
+
+      if let Some(t) = t {
+        if keys.last().is_some_and(|key: &Key<T, V>| key.t == t) {
+          continue;
+        }
+
+        if let (Some(va), Some(vb)) = (self.clamped_sample(t), other.clamped_sample(t)) {
+          push_key(
+            &mut keys,
+            Key::new(t, V::lerp(weight, va, vb), Interpolation::Linear),
+          );
+        }
+      }
+    }
+
+    Spline(keys)
+  }
+
+  /// Reverse the spline, creating a new spline that samples the same values backwards.
+  ///
+  /// The sampling parameter of every key is mapped from `t` to `t_min + t_max - t`, so that the domain is preserved.
+  /// Because the interpolation mode of a segment is carried by its lower key, interpolation modes are shifted to the
+  /// next key and mirrored, so that each segment keeps its shape:
+  ///
+  /// - The threshold of [`Interpolation::Step`] becomes `1 - threshold`.
+  /// - The bias of [`Interpolation::TCB`] is negated. Because the parameters of that mode are used for both keys of a
+  ///   segment, segments are reversed exactly only if both keys share the same parameters.
+  /// - Bézier segments are turned into [`Interpolation::StrokeBezier`] with swapped tangents. A cubic Bézier segment
+  ///   can be reversed exactly only if it is the first segment or if it follows another Bézier segment, as the
+  ///   reversed segment needs its upper key to carry a tangent.
+  /// - The tangents of [`Interpolation::Hermite`] are negated and swapped. For the same reason as Bézier segments, a
+  ///   Hermite segment can be reversed exactly only if it is the first segment or if it follows another Hermite
+  ///   segment.
+  /// - Other modes are symmetric and left as-is.
+  pub fn reverse(&self) -> Self
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T>,
+    M: Clone,
+  {
+    let keys = &self.0;
+    let n = keys.len();
+    let (t_min, t_max) = match self.domain() {
+      Some(domain) => domain,
+      None => return Spline(Vec::new()),
+    };
+
+    let reversed = (0..n).rev().map(|j| {
+      let key = &keys[j];
+      let t = t_max + t_min - key.t;
+
+      // the reversed key governs the segment that ends at the original key
+      let interpolation = match j.checked_sub(1).map(|i| keys[i].interpolation) {
+        Some(Interpolation::Step(threshold)) => Interpolation::Step(T::one() - threshold),
+
+        Some(Interpolation::TCB {
+          tension,
+          continuity,
+          bias,
+        }) => Interpolation::TCB {
+          tension,
+          continuity,
+          bias: -bias,
+        },
+
+        Some(Interpolation::Bezier(u)) | Some(Interpolation::StrokeBezier(_, u)) => {
+          // if the reversed segment cannot be cubic, keep it quadratic when it was originally
+          let cubic = j == 1 || is_bezier(&keys[j - 2].interpolation);
+          let out_tangent = if cubic || is_bezier(&key.interpolation) {
+            bezier_tangents(keys, j - 1).1
+          } else {
+            u
+          };
+          let in_tangent = if is_bezier(&key.interpolation) && j + 1 < n {
+            bezier_tangents(keys, j).0
+          } else {
+            out_tangent
+          };
+
+          Interpolation::StrokeBezier(in_tangent, out_tangent)
+        }
+
+        Some(Interpolation::Hermite {
+          out_tangent: previous,
+          ..
+        }) => {
+          let out_tangent = hermite_in_tangent(key, previous) * -T::one();
+          let in_tangent = match key.interpolation {
+            Interpolation::Hermite { out_tangent, .. } if j + 1 < n => out_tangent * -T::one(),
+            _ => out_tangent,
+          };
+
+          Interpolation::Hermite {
+            in_tangent,
+            out_tangent,
+          }
+        }
+
+        Some(interpolation) => interpolation,
+
+        // the first key becomes the last one, whose interpolation is only used as upper tangent
+        None if is_bezier(&key.interpolation) && n > 1 => {
+          let in_tangent = bezier_tangents(keys, 0).0;
+          Interpolation::StrokeBezier(in_tangent, in_tangent)
+        }
+
+        None => match key.interpolation {
+          Interpolation::Hermite { out_tangent, .. } if n > 1 => {
+            let in_tangent = out_tangent * -T::one();
+            Interpolation::Hermite {
+              in_tangent,
+              out_tangent: in_tangent,
+            }
+          }
+
+          _ => keys[n - 1].interpolation,
+        },
+      };
+
+      Key::with_tag(t, key.value, interpolation, key.tag.clone())
+    });
+
+    Spline(reversed.collect())
+  }
+
+  /// Map the values carried by the spline, creating a new spline.
+  ///
+  /// `f` is applied to the value of every key, while sampling parameters and interpolation modes are kept. Because
+  /// [`Interpolation::Bezier`] and [`Interpolation::StrokeBezier`] carry control points, those are mapped with `f` as
+  /// well. Bézier curves are only preserved by affine maps, so the shape of such segments might change if `f` is not
+  /// affine. The tangents carried by [`Interpolation::Hermite`] are mapped with `f` too; as they are differences of
+  /// values, they are only preserved by linear maps.
+  pub fn map_values<W, F>(&self, f: F) -> Spline<T, W, SIZE, M>
+  where
+    T: Copy,
+    M: Clone,
+    F: Fn(&V) -> W,
+  {
+    let keys = self.0.iter().map(|key| {
+      let interpolation = match key.interpolation {
+        Interpolation::Step(threshold) => Interpolation::Step(threshold),
+        Interpolation::Linear => Interpolation::Linear,
+        Interpolation::Cosine => Interpolation::Cosine,
+        Interpolation::Smoothstep => Interpolation::Smoothstep,
+        Interpolation::Smootherstep => Interpolation::Smootherstep,
+        Interpolation::CatmullRom => Interpolation::CatmullRom,
+        Interpolation::TCB {
+          tension,
+          continuity,
+          bias,
+        } => Interpolation::TCB {
+          tension,
+          continuity,
+          bias,
+        },
+        Interpolation::MonotoneCubic => Interpolation::MonotoneCubic,
+        Interpolation::Akima => Interpolation::Akima,
+        Interpolation::Exponential => Interpolation::Exponential,
+        Interpolation::CircularArc => Interpolation::CircularArc,
+        Interpolation::QuinticHermite => Interpolation::QuinticHermite,
+        Interpolation::Bezier(ref u) => Interpolation::Bezier(f(u)),
+        Interpolation::StrokeBezier(ref u, ref v) => Interpolation::StrokeBezier(f(u), f(v)),
+        Interpolation::Hermite {
+          ref in_tangent,
+          ref out_tangent,
+        } => Interpolation::Hermite {
+          in_tangent: f(in_tangent),
+          out_tangent: f(out_tangent),
+        },
+      };
+
+      Key::with_tag(key.t, f(&key.value), interpolation, key.tag.clone())
+    });
+
+    Spline(keys.collect())
+  }
+
+  /// Transform every key of the spline, consuming it and creating a new spline.
+  ///
+  /// `f` is applied to every key, and can change its sampling parameter, value and interpolation mode at once – e.g. to
+  /// apply a transform mixing them. As `f` might change the order of the sampling parameters, the new keys are sorted
+  /// again, the same way as with [`Spline::from_keys_unsorted`]. Sorting doesn’t fix interpolation modes that depend
+  /// on the direction of the spline, such as [`Interpolation::Bezier`]: see [`Spline::reverse`] for that.
+  pub fn map_keys<T2, V2, M2, F>(self, f: F) -> Spline<T2, V2, SIZE, M2>
+  where
+    T2: PartialOrd,
+    F: FnMut(Key<T, V, M>) -> Key<T2, V2, M2>,
+  {
+    Spline::from_keys_unsorted(self.0.into_iter().map(f).collect())
+  }
+
+  /// Remap the sampling parameters of the spline, creating a new spline.
+  ///
+  /// `f` is applied to the sampling parameter of every key, allowing to warp the time axis non-linearly. Values and
+  /// interpolation modes are kept, and keys are sorted again afterwards.
+  ///
+  /// `f` must be strictly increasing, as non-monotone functions would swap or merge keys and change the shape of the
+  /// spline. This is checked in debug builds.
+  pub fn remap_parameter<F>(&self, f: F) -> Self
+  where
+    T: Copy + PartialOrd,
+    V: Clone,
+    M: Clone,
+    F: Fn(T) -> T,
+  {
+    let keys: Vec<_, SIZE> = self
+      .0
+      .iter()
+      .map(|key| Key {
+        t: f(key.t),
+        ..key.clone()
+      })
+      .collect();
+
+    debug_assert!(
+      keys.windows(2).all(|pair| pair[0].t < pair[1].t),
+      "the remapping function must be strictly increasing"
+    );
+
+    Self::from_vec(keys)
+  }
+
+  /// Shift the sampling parameters of the spline by `delta`.
+  ///
+  /// `delta` is added to the sampling parameter of every key, which moves the whole spline along its time axis without
+  /// changing its shape: sampling the shifted spline at `t + delta` yields the same value as sampling the original
+  /// one at `t`. This is a cheaper shortcut for [`Spline::remap_parameter`] with a constant offset, as the keys
+  /// don’t need to be sorted again.
+  pub fn shift(&mut self, delta: T)
+  where
+    T: Copy + Add<Output = T>,
+  {
+    for key in &mut self.0 {
+      key.t = key.t + delta;
+    }
+  }
+
+  /// Scale the sampling parameters of the spline by `factor`.
+  ///
+  /// The sampling parameter of every key is multiplied by `factor`, which stretches the time axis about zero: sampling
+  /// the scaled spline at `t * factor` yields the same value as sampling the original one at `t`. A factor greater
+  /// than one slows the spline down, a factor smaller than one speeds it up. Combine it with [`Spline::shift`] to scale
+  /// about another sampling parameter, such as the start of the domain.
+  ///
+  /// `factor` must be strictly positive, as other factors would reverse or collapse the keys. This is checked in
+  /// debug builds; see [`Spline::reverse`] to reverse a spline.
+  pub fn scale_parameter(&mut self, factor: T)
+  where
+    T: Float,
+  {
+    debug_assert!(
+      factor > T::zero(),
+      "the scaling factor must be strictly positive"
+    );
+
+    for key in &mut self.0 {
+      key.t = key.t * factor;
+    }
+  }
+
+  /// Invert a strictly monotone spline, creating a new spline sampled by value that yields sampling parameters.
+  ///
+  /// Keys swap their sampling parameters and values, so that sampling the inverse at the value of a key yields its
+  /// sampling parameter. Interpolation modes don’t carry over – the inverse of a cubic curve isn’t cubic –, so every
+  /// key of the inverse uses [`Interpolation::Linear`]: the inverse is exact for [`Interpolation::Linear`] splines,
+  /// and an approximation in between keys otherwise. Use [`Spline::resample`] beforehand to add keys and get a closer
+  /// approximation.
+  ///
+  /// Decreasing splines are inverted too, their keys being reversed so that the inverse is sorted.
+  ///
+  /// # Return
+  ///
+  /// `None` if the values of the keys aren’t strictly monotone, if two keys share the same sampling parameter, or if a
+  /// segment uses [`Interpolation::Step`], which is flat and cannot be inverted. Only keys are checked: curves
+  /// overshooting their keys, e.g. with [`Interpolation::CatmullRom`], are inverted as if they were monotone.
+  pub fn invert(&self) -> Option<Spline<V, T, SIZE>>
+  where
+    T: Copy + PartialOrd,
+    V: Copy + PartialOrd,
+  {
+    let keys = &self.0;
+    let increasing = keys.len() < 2 || keys[0].value < keys[1].value;
+
+    for pair in keys.windows(2) {
+      let monotone = if increasing {
+        pair[0].value < pair[1].value
+      } else {
+        pair[0].value > pair[1].value
+      };
+
+      if !monotone
+        || pair[0].t >= pair[1].t
+        || matches!(pair[0].interpolation, Interpolation::Step(_))
+      {
+        return None;
+      }
+    }
+
+    let inverted = |key: &Key<T, V, M>| Key::new(key.value, key.t, Interpolation::Linear);
+    let keys = if increasing {
+      keys.iter().map(inverted).collect()
+    } else {
+      keys.iter().rev().map(inverted).collect()
+    };
+
+    Some(Spline(keys))
+  }
+
+  /// Find the point of the spline that is the nearest to `target`, returning its sampling parameter and value.
+  ///
+  /// The spline is first sampled at `coarse_steps + 1` sampling parameters evenly spaced across its domain. The
+  /// nearest sample is then refined with Newton’s method on the derivative of the squared distance to `target`, computed
+  /// with the first and second derivatives of the spline, falling back to bisection between the two neighboring samples
+  /// whenever a step overshoots. Samples that cannot be computed – e.g. on [`Interpolation::CatmullRom`] end segments –
+  /// are ignored.
+  ///
+  /// The refinement only finds a local minimum, so `coarse_steps` must be large enough for the curve to be sampled
+  /// more densely than it bends: if the curve comes back close to `target` in between two coarse samples, the wrong
+  /// part of the curve might be picked. Gently curving splines only need a few coarse steps per segment; more steps
+  /// trade speed for robustness.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline is empty or cannot be sampled anywhere.
+  pub fn nearest(&self, target: &V, coarse_steps: usize) -> Option<(T, V)>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    let (t_min, t_max) = self.domain()?;
+    let target = *target;
+    let distance = |t: T| {
+      self
+        .clamped_sample(t)
+        .map(|value| (value - target).norm())
+        .unwrap_or_else(T::infinity)
+    };
+
+    let steps = coarse_steps.max(1);
+    let step = (t_max - t_min) / T::from(steps).unwrap();
+    let (mut best, mut best_distance) = (t_min, T::infinity());
+
+    for i in 0..=steps {
+      let t = if i == steps {
+        t_max
+      } else {
+        t_min + step * T::from(i).unwrap()
+      };
+      let d = distance(t);
+
+      if d < best_distance {
+        best = t;
+        best_distance = d;
+      }
+    }
+
+    if best_distance.is_infinite() {
+      return None;
+    }
+
+    // Newton’s method on the derivative of the squared distance, (C(t) − target)·C′(t), falling back to bisection
+    // whenever a step leaves the bracket around its root
+    let slope = |t: T| {
+      let (value, first, second) = self.sample_with_derivatives(t)?;
+      let offset = value - target;
+      Some((dot(offset, first), dot(first, first) + dot(offset, second)))
+    };
+    let bound = |candidates: [T; 2], approaching: fn(T) -> bool| {
+      candidates
+        .into_iter()
+        .find(|&t| slope(t).is_some_and(|(s, _)| approaching(s)))
+    };
+    let lower = bound([(best - step).max(t_min), best], |s| s < T::zero());
+    let upper = bound([(best + step).min(t_max), best], |s| s > T::zero());
+
+    // without a sign change, the nearest point is the coarse sample itself – e.g. on an end of the domain
+    if let (Some(mut lower), Some(mut upper)) = (lower, upper) {
+      let mut t = best;
+
+      for _ in 0..64 {
+        let (s, ds) = match slope(t) {
+          Some(slope) => slope,
+          None => break,
+        };
+
+        if s == T::zero() {
+          break;
+        } else if s < T::zero() {
+          lower = t;
+        } else {
+          upper = t;
+        }
+
+        let newton = t - s / ds;
+        let next = if ds > T::zero() && lower < newton && newton < upper {
+          newton
+        } else {
+          (lower + upper) / (T::one() + T::one())
+        };
+        let converged = (next - t).abs() <= T::epsilon() * t.abs().max(T::one());
+        t = next;
+
+        if converged {
+          break;
+        }
+      }
+
+      if distance(t) < best_distance {
+        best = t;
+      }
+    }
+
+    self.clamped_sample(best).map(|value| (best, value))
+  }
+
+  /// Create a new spline representing the derivative of this spline with respect to the sampling parameter.
+  ///
+  /// [`Interpolation::Linear`] and [`Interpolation::Step`] segments have a constant derivative, represented by
+  /// [`Interpolation::Step`] keys holding their slope. The derivative of other segments is represented by a quadratic
+  /// Bézier curve – elevated to a cubic one, using [`Interpolation::StrokeBezier`] keys – going through the derivative
+  /// at both ends and in the middle of the segment. This is exact for cubic modes, such as
+  /// [`Interpolation::CatmullRom`] or [`Interpolation::Bezier`], and an approximation otherwise, e.g. with
+  /// [`Interpolation::Cosine`] or [`Interpolation::QuinticHermite`].
+  ///
+  /// Where the derivative jumps, e.g. in between two [`Interpolation::Linear`] segments with different slopes, the new
+  /// spline has two keys sharing the same sampling parameter, so that sampling it at a key yields the derivative of the
+  /// segment starting there; the derivative spline can thus have up to twice as many keys as this spline. Segments
+  /// that cannot be differentiated, such as the first and last segments of [`Interpolation::CatmullRom`], are left
+  /// out.
+  ///
+  /// The derivative spline is sampled with the interpolation of `V`, so values interpolated along a curve rather than
+  /// linearly – see [`Interpolate::lerp_derivative`] – cannot represent their derivatives in between keys: e.g.
+  /// quaternions are normalized when sampled, so only the keys of their derivative spline are meaningful.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the derivative spline has more than `OUT` keys.
+  pub fn derivative_spline<const OUT: usize>(&self) -> Spline<T, V, OUT>
+  where
+    T: Interpolator + Float + FloatConst,
+    V: Interpolate<T> + Linear<T> + PartialEq,
+  {
+    let mut keys = Vec::new();
+    let two_thirds = (T::one() + T::one()) / (T::one() + T::one() + T::one());
+
+    // key ending the previous segment, with its incoming Bézier control point if the segment is curved
+    let mut end: Option<(T, V, Option<V>)> = None;
+
+    for i in 0..self.0.len().saturating_sub(1) {
+      let (cp0, cp1) = (&self.0[i], &self.0[i + 1]);
+      let h = cp1.t - cp0.t;
+      let derivative = |nt: T| segment_derivatives(&self.0, i, nt).map(|(first, _)| first / h);
+
+      // linear interpolations along a curve, e.g. slerp, don’t have a constant derivative
+      let constant = match cp0.interpolation {
+        Interpolation::Step(_) => true,
+        Interpolation::Linear => V::lerp_derivative(T::zero(), cp0.value, cp1.value).is_none(),
+        _ => false,
+      };
+
+      let (start, out_control, segment_end) = if constant {
+        match derivative(T::zero()) {
+          Some(slope) => (slope, None, (cp1.t, slope, None)),
+          None => continue,
+        }
+      } else {
+        match (
+          derivative(T::zero()),
+          derivative(T::one() / (T::one() + T::one())),
+          derivative(T::one()),
+        ) {
+          (Some(d0), Some(dm), Some(d1)) => {
+            // quadratic Bézier control point going through the middle derivative
+            let u = dm + dm - (d0 + d1) / (T::one() + T::one());
+            let out_control = d0 + (u - d0) * two_thirds;
+            let in_control = d1 + (u - d1) * two_thirds;
+
+            (d0, Some(out_control), (cp1.t, d1, Some(in_control)))
+          }
+
+          _ => continue,
+        }
+      };
+
+      // merge the key ending the previous segment with the one starting this segment if the derivative is continuous
+      // and the interpolation of the merged key suits both segments
+      let merged_in_control = match end.take() {
+        Some((t, value, in_control)) => {
+          if t == cp0.t && value == start && (in_control.is_none() || out_control.is_some()) {
+            in_control
+          } else {
+            push_key(&mut keys, derivative_end_key(t, value, in_control));
+            None
+          }
+        }
+
+        None => None,
+      };
+
+      let interpolation = match out_control {
+        Some(out_control) => {
+          Interpolation::StrokeBezier(merged_in_control.unwrap_or(out_control), out_control)
+        }
+        None => Interpolation::Step(T::one()),
+      };
+
+      push_key(&mut keys, Key::new(cp0.t, start, interpolation));
+      end = Some(segment_end);
+    }
+
+    if let Some((t, value, in_control)) = end {
+      push_key(&mut keys, derivative_end_key(t, value, in_control));
+    }
+
+    Spline(keys)
+  }
+
+  /// Compute the axis-aligned bounding box of the spline, returning its component-wise minimum and maximum.
+  ///
+  /// Contrary to the bounds of the keys’ values, this takes into account the parts of the curve that go beyond them,
+  /// e.g. with [`Interpolation::CatmullRom`]. [`Interpolation::Linear`] and [`Interpolation::Step`] segments are
+  /// bounded by their keys; other segments are sampled `samples_per_segment + 1` times, so the box might be slightly
+  /// smaller than the actual curve. Segments that cannot be sampled are ignored.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has fewer than two keys, or if no segment can be sampled.
+  pub fn bounding_box(&self, samples_per_segment: usize) -> Option<(V, V)>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T> + MinMax,
+  {
+    let n = samples_per_segment.max(1);
+    let mut bounds: Option<(V, V)> = None;
+    let mut include = |value: V| {
+      bounds = Some(match bounds {
+        Some((min, max)) => (min.min(value), max.max(value)),
+        None => (value, value),
+      });
+    };
+
+    for (i, keys) in self.0.windows(2).enumerate() {
+      let (t0, t1) = (keys[0].t, keys[1].t);
+
+      match keys[0].interpolation {
+        Interpolation::Linear | Interpolation::Step(_) => {
+          include(keys[0].value);
+          include(keys[1].value);
+        }
+
+        _ if t1 <= t0 => (),
+
+        _ => {
+          for j in 0..=n {
+            let t = if j == n {
+              t1
+            } else {
+              t0 + (t1 - t0) * T::from(j).unwrap() / T::from(n).unwrap()
+            };
+
+            if let Some(value) = self.sample_segment(i, t) {
+              include(value);
+            }
+          }
+        }
+      }
+    }
+
+    bounds
+  }
+
+  /// Compute the arc length of the spline.
+  ///
+  /// The length is estimated by sampling each segment `samples_per_segment` times and summing the distances between
+  /// consecutive samples: the more samples, the more precise it gets. [`Interpolation::Step`] segments — jumping from
+  /// one value to the next one — and segments that cannot be sampled (e.g. the first and last segments of
+  /// [`Interpolation::CatmullRom`]) don’t contribute to the length.
+  pub fn arc_length(&self, samples_per_segment: usize) -> T
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    let mut length = T::zero();
+    self.for_each_chord(samples_per_segment, |_, a, b| {
+      length = length + (b.1 - a.1).norm();
+    });
+
+    length
+  }
+
+  /// Build a table mapping distances along the spline to sampling parameters, for constant-speed playback.
+  ///
+  /// Distances are estimated the same way as with [`Spline::arc_length`], using `samples_per_segment` samples per
+  /// segment; the table holds one entry per sample. Building the table is the expensive part: querying it with
+  /// [`LengthTable::parameter_at_distance`] is cheap enough to be done every frame.
+  ///
+  /// # Return
+  ///
+  /// `None` if no segment contributes to the arc length, e.g. if the spline has fewer than two keys or if all
+  /// segments use [`Interpolation::Step`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the table needs more than `OUT` entries.
+  pub fn build_length_table<const OUT: usize>(
+    &self,
+    samples_per_segment: usize,
+  ) -> Option<LengthTable<T, OUT>>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    let mut entries = Vec::new();
+    let mut push = |entry: (T, T)| {
+      if entries.push(entry).is_err() {
+        panic!("length table capacity ({}) exceeded", OUT);
+      }
+    };
+    let mut travelled = T::zero();
+    let mut previous_t = None;
+
+    self.for_each_chord(samples_per_segment, |_, a, b| {
+      // chords of different segments might not be contiguous, e.g. around step segments
+      if previous_t != Some(a.0) {
+        push((travelled, a.0));
+      }
+
+      travelled = travelled + (b.1 - a.1).norm();
+      push((travelled, b.0));
+      previous_t = Some(b.0);
+    });
+
+    if entries.is_empty() {
+      None
+    } else {
+      Some(LengthTable { entries })
+    }
+  }
+
+  /// Create a new spline whose sampling parameter is the distance along this spline.
+  ///
+  /// The new spline has `n` [`Interpolation::Linear`] keys evenly spaced by distance, from `0` to the arc length of
+  /// this spline, so that sampling it at evenly spaced parameters yields evenly spaced points. Distances are estimated
+  /// the same way as with [`Spline::arc_length`], using `samples_per_segment` samples per segment.
+  ///
+  /// If the arc length is zero, the new spline has a single key, at `0`. It is empty if no segment contributes to the
+  /// arc length, e.g. if all segments use [`Interpolation::Step`].
+  ///
+  /// # Return
+  ///
+  /// `None` if `n` is greater than `OUT`.
+  pub fn reparameterize_by_arc_length<const OUT: usize>(
+    &self,
+    n: usize,
+    samples_per_segment: usize,
+  ) -> Option<Spline<T, V, OUT>>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+  {
+    if n > OUT {
+      return None;
+    }
+
+    let mut keys = Vec::new();
+
+    if n == 0 {
+      return Some(Spline(keys));
+    }
+
+    let length = self.arc_length(samples_per_segment);
+    let distance_at = |k: usize| length * T::from(k).unwrap() / T::from(n - 1).unwrap();
+    let mut travelled = T::zero();
+    let mut last = None;
+
+    self.for_each_chord(samples_per_segment, |i, a, b| {
+      if keys.is_empty() {
+        push_key(&mut keys, Key::new(T::zero(), a.1, Interpolation::Linear));
+      }
+
+      let chord = (b.1 - a.1).norm();
+
+      while chord > T::zero() && keys.len() < n - 1 && distance_at(keys.len()) <= travelled + chord
+      {
+        let distance = distance_at(keys.len());
+        let t = a.0 + (b.0 - a.0) * ((distance - travelled) / chord);
+        let value = self.sample_segment(i, t).unwrap_or(b.1);
+        push_key(&mut keys, Key::new(distance, value, Interpolation::Linear));
+      }
+
+      travelled = travelled + chord;
+      last = Some(b.1);
+    });
+
+    if let Some(last) = last {
+      if length > T::zero() && keys.len() < n {
+        push_key(&mut keys, Key::new(length, last, Interpolation::Linear));
+      }
+    }
+
+    Some(Spline(keys))
+  }
+
+  /// Create a new spline approximating this one with `n` [`Interpolation::Linear`] keys, evenly spaced across the
+  /// domain.
+  ///
+  /// This bakes any spline down to a piecewise linear one. The first and last keys are the ones of this spline, so
+  /// the endpoints are preserved exactly; other keys are sampled in between. The more keys, the closer the
+  /// approximation gets to this spline, at the cost of storage: the error of a linear approximation decreases
+  /// quadratically with the spacing between keys on smooth segments, but discontinuities – e.g. of
+  /// [`Interpolation::Step`] segments – are always smoothed over a whole spacing. Parameters that cannot be sampled,
+  /// e.g. on the first and last segments of [`Interpolation::CatmullRom`], are skipped, so the new spline linearly
+  /// bridges them.
+  ///
+  /// The new spline is empty if this spline is empty or if `n` is zero, and only has the first key if `n` is one.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is greater than `OUT`.
+  pub fn resample<const OUT: usize>(&self, n: usize) -> Spline<T, V, OUT>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+  {
+    assert!(n <= OUT, "spline capacity ({}) exceeded", OUT);
+
+    let mut keys = Vec::new();
+    let (first, last) = match (self.0.first(), self.0.last()) {
+      (Some(first), Some(last)) if n > 0 => (first, last),
+      _ => return Spline(keys),
+    };
+
+    push_key(
+      &mut keys,
+      Key::new(first.t, first.value, Interpolation::Linear),
+    );
+
+    if n == 1 {
+      return Spline(keys);
+    }
+
+    let step = (last.t - first.t) / T::from(n - 1).unwrap();
+
+    for k in 1..n - 1 {
+      let t = first.t + step * T::from(k).unwrap();
+
+      if let Some(value) = self.sample(t) {
+        push_key(&mut keys, Key::new(t, value, Interpolation::Linear));
+      }
+    }
+
+    push_key(
+      &mut keys,
+      Key::new(last.t, last.value, Interpolation::Linear),
+    );
+
+    Spline(keys)
+  }
+
+  /// Create a new spline without the redundant keys of [`Interpolation::Linear`] segments.
+  ///
+  /// Keys are removed with the Ramer–Douglas–Peucker algorithm: a key is dropped if the distance – measured with
+  /// [`Norm::norm`] – between its value and the value the linear interpolation of the kept keys around it yields at
+  /// its sampling parameter is at most `epsilon`. This is useful to decimate dense data, such as captured motion.
+  ///
+  /// Only runs of consecutive [`Interpolation::Linear`] segments are simplified: the first and last keys, as well as
+  /// keys starting or ending a segment using another mode, are always kept, so that other segments are left
+  /// untouched.
+  pub fn simplify(&self, epsilon: T) -> Self
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T> + Linear<T> + Norm<T>,
+    M: Clone,
+  {
+    let keys = &self.0;
+    let mut kept: Vec<bool, SIZE> = keys.iter().map(|_| true).collect();
+    // ranges of keys left to simplify; they never overlap, so there are at most as many as keys
+    let mut ranges: Vec<(usize, usize), SIZE> = Vec::new();
+
+    // indices of the keys bounding the current run of linear segments, if any
+    let mut run_start = None;
+
+    for i in 0..keys.len() {
+      let linear = i + 1 < keys.len() && matches!(keys[i].interpolation, Interpolation::Linear);
+
+      match (run_start, linear) {
+        (None, true) => run_start = Some(i),
+        (Some(start), false) => {
+          let _ = ranges.push((start, i));
+          run_start = None;
+        }
+        _ => (),
+      }
+    }
+
+    while let Some((a, b)) = ranges.pop() {
+      let (ka, kb) = (&keys[a], &keys[b]);
+
+      if b <= a + 1 || kb.t <= ka.t {
+        continue;
+      }
+
+      // key the farthest from the chord between the keys bounding the range
+      let farthest = (a + 1..b)
+        .map(|k| {
+          let chord = V::lerp(keys[k].t.normalize(ka.t, kb.t), ka.value, kb.value);
+          (k, (keys[k].value - chord).norm())
+        })
+        .fold(
+          None,
+          |farthest: Option<(usize, T)>, (k, distance)| match farthest {
+            Some((_, max)) if max >= distance => farthest,
+            _ => Some((k, distance)),
+          },
+        );
+
+      match farthest {
+        Some((k, distance)) if distance > epsilon => {
+          let _ = ranges.push((a, k));
+          let _ = ranges.push((k, b));
+        }
+
+        _ => {
+          for k in a + 1..b {
+            kept[k] = false;
+          }
+        }
+      }
+    }
+
+    Spline(
+      keys
+        .iter()
+        .zip(kept)
+        .filter(|(_, kept)| *kept)
+        .map(|(key, _)| key.clone())
+        .collect(),
+    )
+  }
+
+  /// Create a new spline approximating this one with [`Interpolation::Linear`] segments, by inserting `subdivisions`
+  /// evenly spaced keys inside every segment.
+  ///
+  /// The keys of this spline are all kept, with the same sampling parameters and values, so that the new spline goes
+  /// exactly through them; keys are sampled from this spline in between. This is the inverse of [`Spline::simplify`].
+  ///
+  /// [`Interpolation::Step`] segments are not subdivided and keep their mode, as a linear approximation cannot
+  /// reproduce their discontinuity. Parameters that cannot be sampled, e.g. on the first and last segments of
+  /// [`Interpolation::CatmullRom`], are skipped, so the new spline linearly bridges them.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the new spline has more than `OUT` keys.
+  pub fn densify<const OUT: usize>(&self, subdivisions: usize) -> Spline<T, V, OUT>
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+  {
+    let mut keys = Vec::new();
+    let n = T::from(subdivisions + 1).unwrap();
+
+    for (i, key) in self.0.iter().enumerate() {
+      if let Interpolation::Step(_) = key.interpolation {
+        push_key(&mut keys, Key::new(key.t, key.value, key.interpolation));
+        continue;
+      }
+
+      push_key(&mut keys, Key::new(key.t, key.value, Interpolation::Linear));
+
+      let next = match self.0.get(i + 1) {
+        Some(next) if next.t > key.t => next,
+        _ => continue,
+      };
+
+      for k in 1..=subdivisions {
+        let t = key.t + (next.t - key.t) * T::from(k).unwrap() / n;
+
+        if let Some(value) = self.sample_segment(i, t) {
+          push_key(&mut keys, Key::new(t, value, Interpolation::Linear));
+        }
+      }
+    }
+
+    Spline(keys)
+  }
+
+  /// Call `f` on every chord of the polyline approximating the spline, along with the index of the segment the chord
+  /// belongs to.
+  ///
+  /// Each segment is cut into `samples_per_segment` chords. [`Interpolation::Step`] segments, segments with a zero
+  /// width and segments that cannot be sampled are skipped.
+  fn for_each_chord<F>(&self, samples_per_segment: usize, mut f: F)
+  where
+    T: Interpolator + Float,
+    V: Interpolate<T>,
+    F: FnMut(usize, (T, V), (T, V)),
+  {
+    let n = samples_per_segment.max(1);
+
+    for (i, keys) in self.0.windows(2).enumerate() {
+      let (t0, t1) = (keys[0].t, keys[1].t);
+
+      if t1 <= t0 || matches!(keys[0].interpolation, Interpolation::Step(_)) {
+        continue;
+      }
+
+      let mut previous = match self.sample_segment(i, t0) {
+        Some(value) => (t0, value),
+        None => continue,
+      };
+
+      for j in 1..=n {
+        let t = if j == n {
+          t1
+        } else {
+          t0 + (t1 - t0) * T::from(j).unwrap() / T::from(n).unwrap()
+        };
+
+        if let Some(value) = self.sample_segment(i, t) {
+          f(i, previous, (t, value));
+          previous = (t, value);
+        }
+      }
+    }
+  }
+}
+
+impl<T, V, const SIZE: usize> Spline<T, V, SIZE> {
+  /// Create a new spline out of values, evenly spaced at sampling parameters `0`, `1`, `2`, etc.
+  ///
+  /// Every key uses the same `interpolation` mode.
+  ///
+  /// # Panics
+  ///
+  /// Panics if more than `SIZE` values are yielded, or if an index cannot be represented by `T`.
+  pub fn from_values<I>(values: I, interpolation: Interpolation<T, V>) -> Self
+  where
+    I: IntoIterator<Item = V>,
+    T: Clone + NumCast,
+    V: Clone,
+  {
+    let mut keys = Vec::new();
+
+    for (i, value) in values.into_iter().enumerate() {
+      push_key(
+        &mut keys,
+        Key::new(T::from(i).unwrap(), value, interpolation.clone()),
+      );
+    }
+
+    Spline(keys)
+  }
+
+  /// Create a new smooth Bézier spline going through points, given as pairs of sampling parameters and values.
+  ///
+  /// The handles of every key are computed from its Catmull-Rom tangent – the slope between its neighbors – and the
+  /// slope of the adjacent segment at the first and last keys. Keys use [`Interpolation::StrokeBezier`] so that the
+  /// handles on both sides of a key can have different lengths, keeping the spline C1 continuous even if points are
+  /// unevenly spaced. `smoothness` scales the length of the handles: with `1`, segments match the ones of
+  /// [`Interpolation::CatmullRom`]; lower values tighten the curve around the points, `0` yielding straight lines.
+  ///
+  /// Points don’t have to be sorted; they are sorted the same way as with [`Spline::from_keys_unsorted`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if there are more than `SIZE` points.
+  pub fn auto_bezier(points: &[(T, V)], smoothness: T) -> Self
+  where
+    T: Float,
+    V: Linear<T>,
+  {
+    let mut keys = Vec::new();
+
+    for &(t, value) in points {
+      push_key(&mut keys, Key::new(t, value, Interpolation::Linear));
+    }
+
+    let mut spline = Spline::from_keys_unsorted(keys);
+    let n = spline.0.len();
+
+    if n < 2 {
+      return spline;
+    }
+
+    let three = T::one() + T::one() + T::one();
+    let slope = |a: &Key<T, V>, b: &Key<T, V>| (b.value - a.value) / (b.t - a.t);
+    let tangents: Vec<V, SIZE> = (0..n)
+      .map(|k| {
+        spline
+          .catmull_rom_tangent(k, CatmullRomEndpoints::Clamped)
+          .unwrap_or_else(|| {
+            if k == 0 {
+              slope(&spline.0[0], &spline.0[1])
+            } else {
+              slope(&spline.0[n - 2], &spline.0[n - 1])
+            }
+          })
+      })
+      .collect();
+
+    for k in 0..n {
+      // a handle spans a third of the adjacent segment, mirrored at the end keys
+      let t = |k: usize| spline.0[k].t;
+      let before = if k > 0 { t(k) - t(k - 1) } else { t(1) - t(0) };
+      let after = if k + 1 < n {
+        t(k + 1) - t(k)
+      } else {
+        t(k) - t(k - 1)
+      };
+      let handle = tangents[k] * (smoothness / three);
+      let value = spline.0[k].value;
+
+      spline.0[k].interpolation =
+        Interpolation::StrokeBezier(value - handle * before, value + handle * after);
+    }
+
+    spline
+  }
+
+  /// Create a [`SplineBuilder`] to build a spline key by key.
+  pub fn builder() -> SplineBuilder<T, V, SIZE> {
+    SplineBuilder::new()
+  }
+}
+
+impl<T, const SIZE: usize, M> Spline<T, T, SIZE, M> {
+  /// Find the local minima and maxima of a scalar spline, as pairs of sampling parameters and values, in order.
+  ///
+  /// Extrema inside segments are the parameters at which the analytic derivative changes sign, found by solving the
+  /// derivative – quadratic for cubic modes – over the segment. Segments with [`Interpolation::QuinticHermite`],
+  /// whose derivative is quartic, are searched by bisection instead. Other modes are monotone in between their keys.
+  /// Keys are extrema when the spline goes up on one side and down on the other, even if the derivative jumps there –
+  /// e.g. in between two [`Interpolation::Linear`] segments; extrema found on both sides of a key are merged.
+  ///
+  /// Flat parts of the spline, such as [`Interpolation::Step`] segments, the first and last keys, and segments that
+  /// cannot be differentiated – e.g. the first and last segments of [`Interpolation::CatmullRom`] – are not reported.
+  ///
+  /// # Panics
+  ///
+  /// Panics if more than `OUT` extrema are found.
+  pub fn extrema<const OUT: usize>(&self) -> Vec<(T, T), OUT>
+  where
+    T: Interpolator + Interpolate<T> + Float + FloatConst,
+  {
+    let mut extrema = Vec::new();
+    let tolerance = T::epsilon().sqrt();
+    let mut push = |t: T, value: T, h: T| {
+      // merge extrema found on both sides of a key
+      if let Some(&(last, _)) = extrema.last() {
+        if t - last <= tolerance * h {
+          return;
+        }
+      }
+
+      if extrema.push((t, value)).is_err() {
+        panic!("extrema capacity ({}) exceeded", OUT);
+      }
+    };
+
+    for i in 0..self.0.len().saturating_sub(1) {
+      let (cp0, cp1) = (&self.0[i], &self.0[i + 1]);
+      let h = cp1.t - cp0.t;
+
+      if i > 0 {
+        let left = segment_derivatives(&self.0, i - 1, T::one());
+        let right = segment_derivatives(&self.0, i, T::zero());
+
+        if let (Some(left), Some(right)) = (left, right) {
+          // direction of the spline when reaching and leaving the key, given by the second derivative if flat
+          let flat = |(first, second): (T, T)| first.abs() <= tolerance * second.abs();
+          let reaching = if flat(left) { -left.1 } else { left.0 };
+          let leaving = if flat(right) { right.1 } else { right.0 };
+
+          if reaching * leaving < T::zero() {
+            push(cp0.t, cp0.value, h);
+          }
+        }
+      }
+
+      for nt in self.segment_critical_points(i) {
+        if let Some(value) = self.sample_segment(i, cp0.t + h * nt) {
+          push(cp0.t + h * nt, value, h);
+        }
+      }
+    }
+
+    extrema
+  }
+
+  /// Compute the range of values a scalar spline attains, as its minimum and maximum.
+  ///
+  /// This is the [bounding box](Spline::bounding_box) of the spline, refined with the values at the
+  /// [extrema](Spline::extrema) of its segments. The range is exact – up to rounding – for cubic modes, such as
+  /// [`Interpolation::CatmullRom`], even when they overshoot their keys, and for [`Interpolation::QuinticHermite`]. It
+  /// is estimated with `samples_per_segment + 1` samples for other curved modes, so it might be slightly smaller than
+  /// the actual range there; more samples trade speed for accuracy.
+  ///
+  /// # Return
+  ///
+  /// `None` if the spline has fewer than two keys, or if no segment can be sampled.
+  pub fn value_range(&self, samples_per_segment: usize) -> Option<(T, T)>
+  where
+    T: Interpolator + Interpolate<T> + Float + FloatConst + MinMax,
+  {
+    let (mut min, mut max) = self.bounding_box(samples_per_segment)?;
+
+    for i in 0..self.0.len() - 1 {
+      let (cp0, cp1) = (&self.0[i], &self.0[i + 1]);
+
+      for nt in self.segment_critical_points(i) {
+        if let Some(value) = self.sample_segment(i, cp0.t + (cp1.t - cp0.t) * nt) {
+          min = Float::min(min, value);
+          max = Float::max(max, value);
+        }
+      }
+    }
+
+    Some((min, max))
+  }
+
+  // Normalized sampling parameters, in order and strictly inside the segment starting at the key at index `i`, at
+  // which the derivative of the segment changes sign.
+  fn segment_critical_points(&self, i: usize) -> Vec<T, 8>
+  where
+    T: Interpolator + Interpolate<T> + Float + FloatConst,
+  {
+    let mut points = Vec::new();
+    let derivative = |nt: T| segment_derivatives(&self.0, i, nt).map(|(first, _)| first);
+    let two = T::one() + T::one();
+
+    match self.0[i].interpolation {
+      Interpolation::CatmullRom
+      | Interpolation::TCB { .. }
+      | Interpolation::MonotoneCubic
+      | Interpolation::Akima
+      | Interpolation::Hermite { .. }
+      | Interpolation::Bezier(_)
+      | Interpolation::StrokeBezier(..) => {
+        let (d0, dm, d1) = match (
+          derivative(T::zero()),
+          derivative(T::one() / two),
+          derivative(T::one()),
+        ) {
+          (Some(d0), Some(dm), Some(d1)) => (d0, dm, d1),
+          _ => return points,
+        };
+
+        // coefficients of the quadratic derivative a·nt² + b·nt + c going through the three samples
+        let four = two + two;
+        let a = two * (d0 + d1) - four * dm;
+        let b = four * dm - (two + T::one()) * d0 - d1;
+        let c = d0;
+        let discriminant = b * b - four * a * c;
+
+        // a double root doesn’t change the sign of the derivative
+        if discriminant <= T::zero() {
+          return points;
+        }
+
+        let q = -(b + b.signum() * discriminant.sqrt()) / two;
+        let (r0, r1) = (q / a, c / q);
+
+        for root in [r0.min(r1), r0.max(r1)] {
+          if root > T::zero() && root < T::one() {
+            let _ = points.push(root);
+          }
+        }
+      }
+
+      Interpolation::QuinticHermite => {
+        let n = 8;
+        let at = |k: usize| T::from(k).unwrap() / T::from(n).unwrap();
+        let mut lower = match derivative(T::zero()) {
+          Some(d) => (T::zero(), d),
+          None => return points,
+        };
+
+        for k in 1..=n {
+          let mut upper = (at(k), derivative(at(k)).unwrap_or(lower.1));
+
+          if lower.1 * upper.1 < T::zero() {
+            let (mut a, mut b) = (lower, upper);
+
+            for _ in 0..64 {
+              let mid = (a.0 + b.0) / two;
+              let d = derivative(mid).unwrap_or(a.1);
+
+              if d * a.1 <= T::zero() {
+                b = (mid, d);
+              } else {
+                a = (mid, d);
+              }
+            }
+
+            // at most one sign change per subinterval
+            let _ = points.push((a.0 + b.0) / two);
+          } else if upper.1 == T::zero() && k < n {
+            // keep the sign of the derivative before the zero, so that the sign change is found afterwards
+            upper.1 = lower.1;
+          }
+
+          lower = upper;
+        }
+      }
+
+      // other modes are monotone in between their keys
+      _ => (),
+    }
+
+    points
+  }
+}
+
+// Keys as serialized by the derived Serialize implementation of Spline.
+#[cfg(any(feature = "serialization", feature = "serde"))]
+#[derive(Deserialize)]
+#[serde(
+  rename = "Spline",
+  bound(deserialize = "T: Deserialize<'de>, V: Deserialize<'de>, M: Default + Deserialize<'de>")
+)]
+struct SerializedKeys<T, V, const SIZE: usize, M>(Vec<Key<T, V, M>, SIZE>);
+
+/// Deserialized keys don’t have to be sorted: they are sorted the same way as with [`Spline::from_keys_unsorted`], so
+/// keys sharing the same sampling parameter keep their relative order.
+#[cfg(any(feature = "serialization", feature = "serde"))]
+impl<'de, T, V, const SIZE: usize, M> Deserialize<'de> for Spline<T, V, SIZE, M>
+where
+  T: PartialOrd + Deserialize<'de>,
+  V: Deserialize<'de>,
+  M: Default + Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let SerializedKeys(keys) = SerializedKeys::deserialize(deserializer)?;
+    Ok(Spline::from_keys_unsorted(keys))
+  }
+}
+
+impl<T, V, const SIZE: usize, M> FromIterator<Key<T, V, M>> for Spline<T, V, SIZE, M>
+where
+  T: PartialOrd,
+{
+  /// Collect keys into a spline. The keys don’t have to be sorted.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the iterator yields more than `SIZE` keys.
+  fn from_iter<I>(iter: I) -> Self
+  where
+    I: IntoIterator<Item = Key<T, V, M>>,
+  {
+    let mut keys = Vec::new();
+
+    for key in iter {
+      push_key(&mut keys, key);
+    }
+
+    Self::from_vec(keys)
+  }
+}
+
+impl<T, V, const SIZE: usize, M> Extend<Key<T, V, M>> for Spline<T, V, SIZE, M>
+where
+  T: PartialOrd,
+{
+  /// Add keys into the spline. The keys don’t have to be sorted, and can be interleaved with the keys already present:
+  /// the keys are sorted once all of them are added.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the spline cannot hold all the keys, i.e. if it would end up with more than `SIZE` keys.
+  fn extend<I>(&mut self, iter: I)
+  where
+    I: IntoIterator<Item = Key<T, V, M>>,
+  {
+    for key in iter {
+      push_key(&mut self.0, key);
+    }
+
+    self.internal_sort();
+  }
+}
+
+/// Create a spline out of keys that must already be sorted, checking that they are.
+///
+/// Unlike [`Spline::from_vec`], which sorts the keys, this fails with [`BuildError::NotComparable`] if a sampling
+/// parameter cannot be compared – e.g. NaN – and with [`BuildError::Unsorted`] if the keys are not in ascending order,
+/// which makes it suitable for untrusted input. Keys sharing the same sampling parameter are allowed.
+impl<T, V, const SIZE: usize, M> TryFrom<Vec<Key<T, V, M>, SIZE>> for Spline<T, V, SIZE, M>
+where
+  T: PartialOrd,
+{
+  type Error = BuildError;
+
+  fn try_from(keys: Vec<Key<T, V, M>, SIZE>) -> Result<Self, Self::Error> {
+    for (i, key) in keys.iter().enumerate() {
+      if !is_comparable(&key.t) {
+        return Err(BuildError::NotComparable { key: i });
+      }
+
+      if i > 0 && key.t < keys[i - 1].t {
+        return Err(BuildError::Unsorted { key: i });
+      }
+    }
+
+    Ok(Spline(keys))
+  }
+}
+
+impl<T, V, const SIZE: usize, M> Index<usize> for Spline<T, V, SIZE, M> {
+  type Output = Key<T, V, M>;
+
+  /// Get a key at a given index.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds. See [`Spline::get`] for a non-panicking version.
+  fn index(&self, index: usize) -> &Self::Output {
+    &self.0[index]
+  }
+}
+
+/// A sampled value along with its key index.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SampledWithKey<V> {
+  /// Sampled value.
+  pub value: V,
+
+  /// Key index.
+  pub key: usize,
+}
+
+/// Reasons why sampling a [`Spline`] can fail.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SampleError<T> {
+  /// The spline has no key.
+  Empty,
+
+  /// The sampling parameter lies before the domain of the spline.
+  BelowDomain {
+    /// Sampling parameter of the first key.
+    min: T,
+    /// Sampling parameter of the last key.
+    max: T,
+  },
+
+  /// The sampling parameter lies after the domain of the spline.
+  AboveDomain {
+    /// Sampling parameter of the first key.
+    min: T,
+    /// Sampling parameter of the last key.
+    max: T,
+  },
+
+  /// The interpolation mode of the segment requires more keys around it, e.g. [`Interpolation::CatmullRom`].
+  NotEnoughKeys {
+    /// Index of the key the segment starts at.
+    key: usize,
+  },
+}
+
+impl<T> fmt::Display for SampleError<T>
+where
+  T: fmt::Display,
+{
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      SampleError::Empty => f.write_str("cannot sample an empty spline"),
+
+      SampleError::BelowDomain { min, max } => {
+        write!(
+          f,
+          "sampling parameter below the spline domain [{}, {}]",
+          min, max
+        )
+      }
+
+      SampleError::AboveDomain { min, max } => {
+        write!(
+          f,
+          "sampling parameter above the spline domain [{}, {}]",
+          min, max
+        )
+      }
+
+      SampleError::NotEnoughKeys { key } => write!(
+        f,
+        "not enough keys to interpolate the segment starting at key {}",
+        key
+      ),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for SampleError<T> where T: fmt::Debug + fmt::Display {}
+
+/// A stateful sampler over a [`Spline`].
+///
+/// A cursor remembers the segment it last sampled in. When sampling again, it first checks whether the new sampling
+/// parameter lies in the same segment or in the next one before looking the segment up in the whole spline. This
+/// makes sampling with monotonically increasing sampling parameters – e.g. when animating a value over time – run
+/// in constant time.
+///
+/// Create one with [`Spline::cursor`].
+#[derive(Debug)]
+pub struct SplineCursor<'a, T, V, const SIZE: usize, M = ()> {
+  spline: &'a Spline<T, V, SIZE, M>,
+  segment: usize,
+}
+
+impl<'a, T, V, const SIZE: usize, M> SplineCursor<'a, T, V, SIZE, M> {
+  /// Sample the spline at a given time.
+  ///
+  /// This yields the same result as [`Spline::sample`].
+  pub fn sample(&mut self, t: T) -> Option<V>
+  where
+    T: Interpolator,
+    V: Interpolate<T>,
+  {
+    let keys = &self.spline.0;
+    let contains = |i: usize| i + 1 < keys.len() && keys[i].t <= t && t < keys[i + 1].t;
+
+    let i = if contains(self.segment) {
+      self.segment
+    } else if contains(self.segment + 1) {
+      self.segment + 1
+    } else {
+      search_lower_cp(keys, t)?
+    };
+
+    self.segment = i;
+    self.spline.sample_segment(i, t)
+  }
+}
+
+/// A table mapping distances along a [`Spline`] to sampling parameters.
+///
+/// Sampling the spline at evenly spaced distances – rather than evenly spaced parameters – moves along it at constant
+/// speed. Create one with [`Spline::build_length_table`].
+#[derive(Clone, Debug)]
+pub struct LengthTable<T, const SIZE: usize> {
+  // pairs of distance and sampling parameter, sorted by both
+  entries: Vec<(T, T), SIZE>,
+}
+
+impl<T, const SIZE: usize> LengthTable<T, SIZE> {
+  /// Total length of the spline, as estimated when building the table.
+  pub fn length(&self) -> T
+  where
+    T: Copy,
+  {
+    self.entries[self.entries.len() - 1].0
+  }
+
+  /// Find the sampling parameter at a given distance along the spline.
+  ///
+  /// The entries surrounding `d` are found by binary search and linearly interpolated. `d` is clamped to
+  /// `[0, length]`, so that distances outside of the spline yield the parameters of its ends.
+  pub fn parameter_at_distance(&self, d: T) -> T
+  where
+    T: Float,
+  {
+    let entries = &self.entries;
+    let i = entries.partition_point(|&(distance, _)| distance < d);
+
+    if i == 0 {
+      return entries[0].1;
+    }
+
+    let (upper, lower) = match entries.get(i) {
+      Some(&upper) => (upper, entries[i - 1]),
+      None => return entries[i - 1].1,
+    };
+
+    if upper.0 <= lower.0 {
+      upper.1
+    } else {
+      lower.1 + (upper.1 - lower.1) * ((d - lower.0) / (upper.0 - lower.0))
+    }
+  }
+}
+
+/// A mutable [`Key`].
+///
+/// Mutable keys allow to edit the carried values and the interpolation mode but not the actual
+/// interpolator value as it would invalidate the internal structure of the [`Spline`]. If you
+/// want to achieve this, you’re advised to use [`Spline::replace`].
+#[derive(Debug)]
+pub struct KeyMut<'a, T, V, M = ()> {
+  /// Carried value.
+  pub value: &'a mut V,
+  /// Interpolation mode to use for that key.
+  pub interpolation: &'a mut Interpolation<T, V>,
+  /// User data.
+  pub tag: &'a mut M,
+}
+
+/// Fluent builder of [`Spline`].
+///
+/// Each method appends a key with the corresponding interpolation mode, which is used on the segment starting at that
+/// key. Keys can be appended in any order: they are sorted by [`SplineBuilder::build`], keys sharing the same sampling
+/// parameter keeping their relative order.
+///
+/// Create one with [`Spline::builder`].
+#[derive(Clone, Debug)]
+pub struct SplineBuilder<T, V, const SIZE: usize> {
+  keys: Vec<Key<T, V>, SIZE>,
+}
+
+impl<T, V, const SIZE: usize> Default for SplineBuilder<T, V, SIZE> {
+  fn default() -> Self {
+    SplineBuilder { keys: Vec::new() }
+  }
+}
+
+impl<T, V, const SIZE: usize> SplineBuilder<T, V, SIZE> {
+  /// Create a builder with no key.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Append a key.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the builder already has `SIZE` keys.
+  pub fn key(mut self, key: Key<T, V>) -> Self {
+    push_key(&mut self.keys, key);
+    self
+  }
+
+  /// Append a key using [`Interpolation::Linear`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the builder already has `SIZE` keys.
+  pub fn linear(self, t: T, value: V) -> Self {
+    self.key(Key::new(t, value, Interpolation::Linear))
+  }
+
+  /// Append a key using [`Interpolation::Step`] with the given threshold.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the builder already has `SIZE` keys.
+  pub fn step(self, t: T, value: V, threshold: T) -> Self {
+    self.key(Key::new(t, value, Interpolation::Step(threshold)))
+  }
+
+  /// Append a key using [`Interpolation::CatmullRom`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the builder already has `SIZE` keys.
+  pub fn catmull_rom(self, t: T, value: V) -> Self {
+    self.key(Key::new(t, value, Interpolation::CatmullRom))
+  }
+
+  /// Append a key using [`Interpolation::Bezier`] with the given output tangent.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the builder already has `SIZE` keys.
+  pub fn bezier(self, t: T, value: V, tangent: V) -> Self {
+    self.key(Key::new(t, value, Interpolation::Bezier(tangent)))
+  }
+
+  /// Build the spline, sorting its keys.
+  ///
+  /// Fails with [`BuildError::NotEnoughKeys`] if fewer than two keys were appended, as such a spline cannot be sampled.
+  pub fn build(self) -> Result<Spline<T, V, SIZE>, BuildError>
+  where
+    T: PartialOrd,
+  {
+    let len = self.keys.len();
+
+    if len < 2 {
+      return Err(BuildError::NotEnoughKeys { len });
+    }
+
+    Ok(Spline::from_keys_unsorted(self.keys))
+  }
+}
+
+/// Reasons why building a [`Spline`] – with [`SplineBuilder`] or by converting keys with [`TryFrom`] – can fail.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BuildError {
+  /// Fewer than two keys were provided, so the spline could not be sampled.
+  NotEnoughKeys {
+    /// Number of provided keys.
+    len: usize,
+  },
+
+  /// The sampling parameter of a key cannot be compared – e.g. it is NaN.
+  NotComparable {
+    /// Index of the key.
+    key: usize,
+  },
+
+  /// A key has a lower sampling parameter than the key before it.
+  Unsorted {
+    /// Index of the key.
+    key: usize,
+  },
+}
+
+impl fmt::Display for BuildError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      BuildError::NotEnoughKeys { len } => write!(
+        f,
+        "a spline needs at least two keys to be sampled, but {} were provided",
+        len
+      ),
+
+      BuildError::NotComparable { key } => write!(
+        f,
+        "the sampling parameter of key {} cannot be compared",
+        key
+      ),
+
+      BuildError::Unsorted { key } => write!(
+        f,
+        "key {} has a lower sampling parameter than the key before it",
+        key
+      ),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+// Tangent of the Hermite segment reaching `key`, whose lower key leaves with `out_tangent`.
+fn hermite_in_tangent<T, V, M>(key: &Key<T, V, M>, out_tangent: V) -> V
+where
+  V: Copy,
+{
+  match key.interpolation {
+    Interpolation::Hermite { in_tangent, .. } => in_tangent,
+    _ => out_tangent,
+  }
+}
+
+// Key ending a segment of a derivative spline, reached with the Bézier control point `in_control` if the segment is
+// curved.
+fn derivative_end_key<T, V>(t: T, value: V, in_control: Option<V>) -> Key<T, V>
+where
+  T: Float,
+  V: Copy,
+{
+  let interpolation = match in_control {
+    Some(in_control) => Interpolation::StrokeBezier(in_control, in_control),
+    None => Interpolation::Step(T::one()),
+  };
+
+  Key::new(t, value, interpolation)
+}
+
+// Control points of the cubic Bézier curve of the Bézier segment starting at the key at index `i`.
+//
+// Quadratic Bézier segments are elevated to cubic ones.
+fn bezier_tangents<T, V, M>(keys: &[Key<T, V, M>], i: usize) -> (V, V)
+where
+  T: Float,
+  V: Linear<T>,
+{
+  let (a, b) = (keys[i].value, keys[i + 1].value);
+  let two_thirds = (T::one() + T::one()) / (T::one() + T::one() + T::one());
+  let u = match keys[i].interpolation {
+    Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => u,
+    _ => a,
+  };
+
+  match keys[i + 1].interpolation {
+    Interpolation::Bezier(v) => (u, b + b - v),
+    Interpolation::StrokeBezier(v, _) => (u, v),
+    _ => (a + (u - a) * two_thirds, b + (u - b) * two_thirds),
+  }
+}
+
+// Whether an interpolation mode is one of the Bézier ones.
+fn is_bezier<T, V>(interpolation: &Interpolation<T, V>) -> bool {
+  matches!(
+    interpolation,
+    Interpolation::Bezier(_) | Interpolation::StrokeBezier(_, _)
+  )
+}
+
+// Whether a sampling parameter can be compared, i.e. is not NaN.
+fn is_comparable<T>(t: &T) -> bool
+where
+  T: PartialOrd,
+{
+  t.partial_cmp(t).is_some()
+}
+
+// Push a key, panicking if the storage is full.
+fn push_key<T, V, M, const SIZE: usize>(keys: &mut Vec<Key<T, V, M>, SIZE>, key: Key<T, V, M>) {
+  if keys.push(key).is_err() {
+    panic!("spline capacity ({}) exceeded", SIZE);
+  }
+}
+
+// Insert a key at a given index, panicking if the storage is full.
+fn insert_key<T, V, M, const SIZE: usize>(
+  keys: &mut Vec<Key<T, V, M>, SIZE>,
+  index: usize,
+  key: Key<T, V, M>,
+) {
+  if keys.insert(index, key).is_err() {
+    panic!("spline capacity ({}) exceeded", SIZE);
+  }
+}
 
 // Find the lower control point corresponding to a given time.
-// It has the property to have a timestamp smaller or equal to t
-fn search_lower_cp<T, V>(cps: &[Key<T, V>], t: T) -> Option<usize>
+// It has the property to have a timestamp smaller or equal to t, the last key being mapped to the last segment. If
+// several keys share that timestamp, the last one is picked, so that the segment found is never empty (but on the last
+// key).
+fn search_lower_cp<T, V, M>(cps: &[Key<T, V, M>], t: T) -> Option<usize>
 where
   T: PartialOrd,
 {
@@ -333,11 +3055,359 @@ where
   if len < 2 {
     return None;
   }
-  match cps.binary_search_by(|key| key.t.partial_cmp(&t).unwrap()) {
-    Err(i) if i >= len => None,
-    Err(i) if i == 0 => None,
-    Err(i) => Some(i - 1),
-    Ok(i) if i == len - 1 => None,
-    Ok(i) => Some(i),
+  match cps.partition_point(|key| key.t <= t) {
+    0 => None,
+    i if i < len => Some(i - 1),
+    _ if cps[len - 1].t == t => Some(len - 2),
+    _ => None,
+  }
+}
+
+// Keys used by the Akima interpolation of the segment starting at index `i`, missing neighbors being replaced by the
+// closest available key.
+fn akima_keys<T, V, M>(keys: &[Key<T, V, M>], i: usize) -> [(T, V); 6]
+where
+  T: Copy,
+  V: Copy,
+{
+  let last = keys.len() - 1;
+  let key = |j: usize| (keys[j].t, keys[j].value);
+
+  [
+    key(i.saturating_sub(2)),
+    key(i.saturating_sub(1)),
+    key(i),
+    key(i + 1),
+    key((i + 2).min(last)),
+    key((i + 3).min(last)),
+  ]
+}
+
+// Tangent at its first key of the circular arc segment starting at the key at index `i`: the derivative at the end
+// of the previous segment, so that the curve is smooth, or – on the first segment, or if the previous segment has no
+// derivative there – the tangent of the circle given by circle_key_tangent.
+#[allow(dead_code)]
+pub(crate) fn circular_arc_tangent<T, V, M>(keys: &[Key<T, V, M>], i: usize) -> V
+where
+  T: Interpolator + Float + FloatConst,
+  V: Interpolate<T> + Linear<T> + Norm<T>,
+{
+  i.checked_sub(1)
+    .and_then(|j| segment_derivatives(keys, j, T::one()))
+    .map(|(first, _)| first)
+    .filter(|first| first.norm() > T::zero())
+    .unwrap_or_else(|| circle_key_tangent(keys, i, |value| value))
+}
+
+// Tangent at its first key of the circular arc segment starting at the key at index `i`, along the circle passing
+// through both keys of the segment and the previous key, or the next one for the first segment. The segment is a
+// straight line if the spline only has two keys. Values are converted to vectors with `vector`, so that values that
+// cannot be differentiated, such as points, trace arcs as well.
+#[allow(dead_code)]
+pub(crate) fn circle_key_tangent<T, V, W, M>(
+  keys: &[Key<T, V, M>],
+  i: usize,
+  vector: impl Fn(V) -> W,
+) -> W
+where
+  T: Float,
+  V: Copy,
+  W: Linear<T> + Norm<T>,
+{
+  let p = if i > 0 {
+    &keys[i - 1]
+  } else {
+    keys.get(i + 2).unwrap_or(&keys[i + 1])
+  };
+
+  circle_tangent(
+    vector(keys[i].value),
+    vector(keys[i + 1].value),
+    vector(p.value),
+  )
+}
+
+// First and second derivatives of the segment starting at the key at index `i`, with respect to its normalized
+// sampling parameter `nt`.
+//
+// Segments with a zero width – i.e. jumps – have no derivative.
+fn segment_derivatives<T, V, M>(keys: &[Key<T, V, M>], i: usize, nt: T) -> Option<(V, V)>
+where
+  T: Interpolator + Float + FloatConst,
+  V: Interpolate<T> + Linear<T>,
+{
+  let cp0 = &keys[i];
+  let cp1 = &keys[i + 1];
+  let h = cp1.t - cp0.t;
+
+  if h <= T::zero() {
+    return None;
+  }
+
+  let two = T::one() + T::one();
+  let six = two + two + two;
+  let zero = cp0.value * T::zero();
+
+  let derivatives = match cp0.interpolation {
+    Interpolation::Step(_) => (zero, zero),
+
+    Interpolation::Linear => {
+      eased_lerp_derivatives(nt, cp0.value, cp1.value, |nt| (nt, T::one(), T::zero()))
+    }
+
+    Interpolation::Cosine => {
+      let pi = T::PI();
+      eased_lerp_derivatives(nt, cp0.value, cp1.value, |nt| {
+        (
+          (T::one() - (nt * pi).cos()) / two,
+          pi * (nt * pi).sin() / two,
+          pi * pi * (nt * pi).cos() / two,
+        )
+      })
+    }
+
+    Interpolation::Smoothstep => eased_lerp_derivatives(nt, cp0.value, cp1.value, |nt| {
+      (
+        nt * nt * (two + T::one() - two * nt),
+        six * nt * (T::one() - nt),
+        six * (T::one() - two * nt),
+      )
+    }),
+
+    Interpolation::Smootherstep => {
+      let thirty = T::from(30.).unwrap();
+      eased_lerp_derivatives(nt, cp0.value, cp1.value, |nt| {
+        let one_nt = T::one() - nt;
+        (
+          nt * nt * nt * (nt * (nt * six - T::from(15.).unwrap()) + T::from(10.).unwrap()),
+          thirty * nt * nt * one_nt * one_nt,
+          thirty * two * nt * one_nt * (one_nt - nt),
+        )
+      })
+    }
+
+    Interpolation::CatmullRom => {
+      // the last key can be sampled without the key following it, but not differentiated
+      let cpm0 = &keys[i.checked_sub(1)?];
+      let cpm1 = keys.get(i + 2)?;
+      let k = |key: &Key<T, V, M>| (key.t, key.value);
+      let derivative = |nt| V::cubic_hermite_derivative(nt, k(cpm0), k(cp0), k(cp1), k(cpm1));
+
+      if derivative(nt).is_some() {
+        numerical_derivatives(nt, |nt| derivative(nt).unwrap_or(cp1.value - cp0.value))
+      } else {
+        let m0 = (cp1.value - cpm0.value) / (cp1.t - cpm0.t) * h;
+        let m1 = (cpm1.value - cp0.value) / (cpm1.t - cp0.t) * h;
+
+        hermite_derivatives(nt, cp0.value, m0, cp1.value, m1)
+      }
+    }
+
+    Interpolation::TCB {
+      tension,
+      continuity,
+      bias,
+    } => {
+      let one = T::one();
+      let cpm0 = &keys[i.saturating_sub(1)];
+      let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+      let (tension_b, continuity_b, bias_b) = match cp1.interpolation {
+        Interpolation::TCB {
+          tension,
+          continuity,
+          bias,
+        } => (tension, continuity, bias),
+        _ => (tension, continuity, bias),
+      };
+      let m0 = ((cp0.value - cpm0.value) * ((one + bias) * (one + continuity))
+        + (cp1.value - cp0.value) * ((one - bias) * (one - continuity)))
+        * ((one - tension) / (cp1.t - cpm0.t) * h);
+      let m1 = ((cp1.value - cp0.value) * ((one + bias_b) * (one - continuity_b))
+        + (cpm1.value - cp1.value) * ((one - bias_b) * (one + continuity_b)))
+        * ((one - tension_b) / (cpm1.t - cp0.t) * h);
+
+      hermite_derivatives(nt, cp0.value, m0, cp1.value, m1)
+    }
+
+    Interpolation::MonotoneCubic => {
+      let cpm0 = &keys[i.saturating_sub(1)];
+      let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+      let (m0, m1) = V::monotone_tangents(
+        (cpm0.t, cpm0.value),
+        (cp0.t, cp0.value),
+        (cp1.t, cp1.value),
+        (cpm1.t, cpm1.value),
+      )
+      .unwrap_or_else(|| {
+        (
+          (cp1.value - cpm0.value) / (cp1.t - cpm0.t) * h,
+          (cpm1.value - cp0.value) / (cpm1.t - cp0.t) * h,
+        )
+      });
+
+      hermite_derivatives(nt, cp0.value, m0, cp1.value, m1)
+    }
+
+    Interpolation::Akima => {
+      let (m0, m1) = V::akima_tangents(akima_keys(keys, i)).unwrap_or_else(|| {
+        let cpm0 = &keys[i.saturating_sub(1)];
+        let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+        (
+          (cp1.value - cpm0.value) / (cp1.t - cpm0.t) * h,
+          (cpm1.value - cp0.value) / (cpm1.t - cp0.t) * h,
+        )
+      });
+
+      hermite_derivatives(nt, cp0.value, m0, cp1.value, m1)
+    }
+
+    Interpolation::Exponential => numerical_derivatives(nt, |nt| {
+      V::exponential_derivative(nt, cp0.value, cp1.value).unwrap_or(cp1.value - cp0.value)
+    }),
+
+    Interpolation::CircularArc => {
+      V::circular_arc_derivatives(nt, keys, i).unwrap_or((cp1.value - cp0.value, zero))
+    }
+
+    Interpolation::QuinticHermite => {
+      let cpm0 = &keys[i.saturating_sub(1)];
+      let cpm1 = keys.get(i + 2).unwrap_or(cp1);
+      let m0 = (cp1.value - cpm0.value) / (cp1.t - cpm0.t) * h;
+      let m1 = (cpm1.value - cp0.value) / (cpm1.t - cp0.t) * h;
+      let slope = (cp1.value - cp0.value) / h;
+      let acc0 = if cpm0.t < cp0.t {
+        (slope - (cp0.value - cpm0.value) / (cp0.t - cpm0.t)) * (two / (cp1.t - cpm0.t) * h * h)
+      } else {
+        zero
+      };
+      let acc1 = if cpm1.t > cp1.t {
+        ((cpm1.value - cp1.value) / (cpm1.t - cp1.t) - slope) * (two / (cpm1.t - cp0.t) * h * h)
+      } else {
+        zero
+      };
+
+      quintic_hermite_derivatives(nt, cp0.value, m0, acc0, acc1, m1, cp1.value)
+    }
+
+    Interpolation::Hermite { out_tangent, .. } => {
+      let in_tangent = hermite_in_tangent(cp1, out_tangent);
+      hermite_derivatives(nt, cp0.value, out_tangent * h, cp1.value, in_tangent * h)
+    }
+
+    Interpolation::Bezier(u) | Interpolation::StrokeBezier(_, u) => match cp1.interpolation {
+      Interpolation::Bezier(v) => {
+        cubic_bezier_derivatives(nt, cp0.value, u, cp1.value + cp1.value - v, cp1.value)
+      }
+
+      Interpolation::StrokeBezier(v, _) => cubic_bezier_derivatives(nt, cp0.value, u, v, cp1.value),
+
+      _ => (
+        ((u - cp0.value) * (T::one() - nt) + (cp1.value - u) * nt) * two,
+        (cp0.value - u - u + cp1.value) * two,
+      ),
+    },
+  };
+
+  Some(derivatives)
+}
+
+// First and second derivatives of a cubic Hermite curve with respect to its normalized parameter.
+fn hermite_derivatives<T, V>(t: T, a: V, m0: V, b: V, m1: V) -> (V, V)
+where
+  T: Float,
+  V: Linear<T>,
+{
+  let two = T::one() + T::one();
+  let three = two + T::one();
+  let six = three * two;
+  let t2 = t * t;
+
+  (
+    a * (six * t2 - six * t)
+      + m0 * (three * t2 - two * two * t + T::one())
+      + b * (six * t - six * t2)
+      + m1 * (three * t2 - two * t),
+    (a - b) * (six * (t + t - T::one())) + m0 * (six * t - two * two) + m1 * (six * t - two),
+  )
+}
+
+// First and second derivatives of a quintic Hermite curve with respect to its normalized parameter.
+fn quintic_hermite_derivatives<T, V>(t: T, a: V, m0: V, acc0: V, acc1: V, m1: V, b: V) -> (V, V)
+where
+  T: Float,
+  V: Linear<T>,
+{
+  let c = |x: f32| T::from(x).unwrap();
+  let t2 = t * t;
+  let t3 = t2 * t;
+  let t4 = t3 * t;
+
+  (
+    (b - a) * (c(30.) * (t2 - t3 - t3 + t4))
+      + m0 * (T::one() - c(18.) * t2 + c(32.) * t3 - c(15.) * t4)
+      + acc0 * (t - c(4.5) * t2 + c(6.) * t3 - c(2.5) * t4)
+      + acc1 * (c(1.5) * t2 - c(4.) * t3 + c(2.5) * t4)
+      + m1 * (c(28.) * t3 - c(12.) * t2 - c(15.) * t4),
+    (b - a) * (c(60.) * t - c(180.) * t2 + c(120.) * t3)
+      + m0 * (c(96.) * t2 - c(36.) * t - c(60.) * t3)
+      + acc0 * (T::one() - c(9.) * t + c(18.) * t2 - c(10.) * t3)
+      + acc1 * (c(3.) * t - c(12.) * t2 + c(10.) * t3)
+      + m1 * (c(84.) * t2 - c(24.) * t - c(60.) * t3),
+  )
+}
+
+// First and second derivatives of a cubic Bézier curve with respect to its normalized parameter.
+fn cubic_bezier_derivatives<T, V>(t: T, a: V, u: V, v: V, b: V) -> (V, V)
+where
+  T: Float,
+  V: Linear<T>,
+{
+  let one_t = T::one() - t;
+  let three = T::one() + T::one() + T::one();
+  let six = three + three;
+
+  (
+    (u - a) * (three * one_t * one_t)
+      + (v - u) * (three * (one_t + one_t) * t)
+      + (b - v) * (three * t * t),
+    (v - u - u + a) * (six * one_t) + (b - v - v + u) * (six * t),
+  )
+}
+
+// First and second derivatives of Interpolate::lerp between `a` and `b`, with respect to a normalized parameter eased
+// by `easing`, which yields the eased parameter along with its first and second derivatives.
+//
+// The second derivative is estimated numerically for values overriding Interpolate::lerp_derivative.
+fn eased_lerp_derivatives<T, V>(t: T, a: V, b: V, easing: impl Fn(T) -> (T, T, T)) -> (V, V)
+where
+  T: Float,
+  V: Interpolate<T> + Linear<T>,
+{
+  let (eased, first, second) = easing(t);
+
+  match V::lerp_derivative(eased, a, b) {
+    None => ((b - a) * first, (b - a) * second),
+
+    Some(_) => numerical_derivatives(t, |t| {
+      let (eased, first, _) = easing(t);
+      V::lerp_derivative(eased, a, b).unwrap_or(b - a) * first
+    }),
   }
 }
+
+// First derivative of a curve with respect to its normalized parameter, along with its second derivative estimated
+// with a central difference (one-sided at the ends of the segment).
+fn numerical_derivatives<T, V>(t: T, derivative: impl Fn(T) -> V) -> (V, V)
+where
+  T: Float,
+  V: Linear<T>,
+{
+  let step = T::epsilon().cbrt();
+  let lower = (t - step).max(T::zero());
+  let upper = (t + step).min(T::one());
+
+  (
+    derivative(t),
+    (derivative(upper) - derivative(lower)) / (upper - lower),
+  )
+}
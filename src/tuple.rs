@@ -0,0 +1,117 @@
+//! Tuple implementors.
+//!
+//! Tuples of up to four values are interpolated component-wise, allowing to interpolate heterogeneous bundles of
+//! values – e.g. a position along with an opacity – with a single spline. All components share the same sampling
+//! parameter type.
+//!
+//! Tuples don’t support arithmetic operations, so the operations on splines requiring [`Linear`] values – e.g.
+//! derivatives or arc length – are not available to them.
+//!
+//! [`Linear`]: crate::interpolate::Linear
+
+use crate::interpolate::{Interpolate, MinMax};
+
+macro_rules! impl_Interpolate_tuple {
+  ($($v:ident . $i:tt),+) => {
+    impl<T, $($v),+> Interpolate<T> for ($($v,)+)
+    where
+      T: Copy,
+      $($v: Interpolate<T>),+
+    {
+      fn step(t: T, threshold: T, a: Self, b: Self) -> Self {
+        ($($v::step(t, threshold, a.$i, b.$i),)+)
+      }
+
+      fn lerp(t: T, a: Self, b: Self) -> Self {
+        ($($v::lerp(t, a.$i, b.$i),)+)
+      }
+
+      fn cosine(t: T, a: Self, b: Self) -> Self {
+        ($($v::cosine(t, a.$i, b.$i),)+)
+      }
+
+      fn smoothstep(t: T, a: Self, b: Self) -> Self {
+        ($($v::smoothstep(t, a.$i, b.$i),)+)
+      }
+
+      fn smootherstep(t: T, a: Self, b: Self) -> Self {
+        ($($v::smootherstep(t, a.$i, b.$i),)+)
+      }
+
+      fn cubic_hermite(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self {
+        ($($v::cubic_hermite(t, (x.0, x.1.$i), (a.0, a.1.$i), (b.0, b.1.$i), (y.0, y.1.$i)),)+)
+      }
+
+      fn hermite(t: T, a: (T, Self), b: (T, Self), m0: Self, m1: Self) -> Self {
+        ($($v::hermite(t, (a.0, a.1.$i), (b.0, b.1.$i), m0.$i, m1.$i),)+)
+      }
+
+      fn monotone_cubic(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self {
+        ($($v::monotone_cubic(t, (x.0, x.1.$i), (a.0, a.1.$i), (b.0, b.1.$i), (y.0, y.1.$i)),)+)
+      }
+
+      fn akima(t: T, keys: [(T, Self); 6]) -> Self {
+        ($($v::akima(t, keys.map(|(t, v)| (t, v.$i))),)+)
+      }
+
+      fn exponential(t: T, a: Self, b: Self) -> Self {
+        ($($v::exponential(t, a.$i, b.$i),)+)
+      }
+
+      fn quintic_hermite(t: T, x: (T, Self), a: (T, Self), b: (T, Self), y: (T, Self)) -> Self {
+        ($($v::quintic_hermite(t, (x.0, x.1.$i), (a.0, a.1.$i), (b.0, b.1.$i), (y.0, y.1.$i)),)+)
+      }
+
+      fn kochanek_bartels(
+        t: T,
+        x: (T, Self),
+        a: (T, Self),
+        b: (T, Self),
+        y: (T, Self),
+        tcb_a: (T, T, T),
+        tcb_b: (T, T, T),
+      ) -> Self {
+        ($(
+          $v::kochanek_bartels(
+            t,
+            (x.0, x.1.$i),
+            (a.0, a.1.$i),
+            (b.0, b.1.$i),
+            (y.0, y.1.$i),
+            tcb_a,
+            tcb_b,
+          ),
+        )+)
+      }
+
+      fn quadratic_bezier(t: T, a: Self, u: Self, b: Self) -> Self {
+        ($($v::quadratic_bezier(t, a.$i, u.$i, b.$i),)+)
+      }
+
+      fn cubic_bezier(t: T, a: Self, u: Self, v: Self, b: Self) -> Self {
+        ($($v::cubic_bezier(t, a.$i, u.$i, v.$i, b.$i),)+)
+      }
+
+      fn cubic_bezier_mirrored(t: T, a: Self, u: Self, v: Self, b: Self) -> Self {
+        ($($v::cubic_bezier_mirrored(t, a.$i, u.$i, v.$i, b.$i),)+)
+      }
+    }
+
+    impl<$($v),+> MinMax for ($($v,)+)
+    where
+      $($v: MinMax),+
+    {
+      fn min(self, other: Self) -> Self {
+        ($(MinMax::min(self.$i, other.$i),)+)
+      }
+
+      fn max(self, other: Self) -> Self {
+        ($(MinMax::max(self.$i, other.$i),)+)
+      }
+    }
+  };
+}
+
+impl_Interpolate_tuple!(A.0, B.1);
+impl_Interpolate_tuple!(A.0, B.1, C.2);
+impl_Interpolate_tuple!(A.0, B.1, C.2, D.3);
@@ -0,0 +1,62 @@
+//! [ultraviolet] implementors.
+//!
+//! Rotors are interpolated as the unit quaternions they are isomorphic to, so that linear interpolation is a rotor
+//! slerp and Catmull-Rom interpolation uses squad, as for the quaternion types of the other math crates.
+//!
+//! [ultraviolet]: https://crates.io/crates/ultraviolet
+
+use crate::impl_Interpolate;
+use crate::interpolate::{impl_circular_arc, MinMax, Norm};
+use crate::quaternion::impl_Interpolate_quaternion;
+use ultraviolet::{Rotor3, Vec2, Vec3, Vec4};
+
+impl_Interpolate!(f32, Vec2, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vec3, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+impl_Interpolate!(f32, Vec4, core::f32::consts::PI, {
+  impl_circular_arc!(f32);
+});
+
+impl_Interpolate_quaternion!(
+  f32,
+  Rotor3,
+  Vec4,
+  Rotor3::into_quaternion_array,
+  Rotor3::from_quaternion_array
+);
+
+macro_rules! impl_Norm {
+  ($t:ty, $v:ty) => {
+    impl Norm<$t> for $v {
+      fn norm(self) -> $t {
+        self.mag()
+      }
+    }
+  };
+}
+
+impl_Norm!(f32, Vec2);
+impl_Norm!(f32, Vec3);
+impl_Norm!(f32, Vec4);
+impl_Norm!(f32, Rotor3);
+
+macro_rules! impl_MinMax {
+  ($v:ty) => {
+    impl MinMax for $v {
+      fn min(self, other: Self) -> Self {
+        self.min_by_component(other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        self.max_by_component(other)
+      }
+    }
+  };
+}
+
+impl_MinMax!(Vec2);
+impl_MinMax!(Vec3);
+impl_MinMax!(Vec4);
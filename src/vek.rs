@@ -0,0 +1,90 @@
+//! [vek] implementors.
+//!
+//! Quaternions are interpolated along the shortest arc, as for the quaternion types of the other math crates.
+//!
+//! [vek]: https://crates.io/crates/vek
+
+use crate::impl_Interpolate;
+use crate::interpolate::{impl_circular_arc, MinMax, Norm};
+use crate::quaternion::impl_Interpolate_quaternion;
+use num_traits::Float;
+use vek::{Quaternion, Vec2, Vec3, Vec4};
+
+macro_rules! impl_Interpolate_vek {
+  ($t:ty, $pi:expr) => {
+    impl_Interpolate!($t, Vec2<$t>, $pi, {
+      impl_circular_arc!($t);
+    });
+    impl_Interpolate!($t, Vec3<$t>, $pi, {
+      impl_circular_arc!($t);
+    });
+    impl_Interpolate!($t, Vec4<$t>, $pi, {
+      impl_circular_arc!($t);
+    });
+
+    impl_Interpolate_quaternion!(
+      $t,
+      Quaternion<$t>,
+      [$t; 4],
+      |q: Quaternion<$t>| q.into_vec4().into_array(),
+      |[x, y, z, w]: [$t; 4]| Quaternion::from_xyzw(x, y, z, w)
+    );
+
+    impl Norm<$t> for Vec2<$t> {
+      fn norm(self) -> $t {
+        Float::sqrt(self.magnitude_squared())
+      }
+    }
+
+    impl Norm<$t> for Vec3<$t> {
+      fn norm(self) -> $t {
+        Float::sqrt(self.magnitude_squared())
+      }
+    }
+
+    impl Norm<$t> for Vec4<$t> {
+      fn norm(self) -> $t {
+        Float::sqrt(self.magnitude_squared())
+      }
+    }
+
+    impl Norm<$t> for Quaternion<$t> {
+      fn norm(self) -> $t {
+        Float::sqrt(self.magnitude_squared())
+      }
+    }
+
+    impl MinMax for Vec2<$t> {
+      fn min(self, other: Self) -> Self {
+        Vec2::partial_min(self, other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        Vec2::partial_max(self, other)
+      }
+    }
+
+    impl MinMax for Vec3<$t> {
+      fn min(self, other: Self) -> Self {
+        Vec3::partial_min(self, other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        Vec3::partial_max(self, other)
+      }
+    }
+
+    impl MinMax for Vec4<$t> {
+      fn min(self, other: Self) -> Self {
+        Vec4::partial_min(self, other)
+      }
+
+      fn max(self, other: Self) -> Self {
+        Vec4::partial_max(self, other)
+      }
+    }
+  };
+}
+
+impl_Interpolate_vek!(f32, core::f32::consts::PI);
+impl_Interpolate_vek!(f64, core::f64::consts::PI);
@@ -0,0 +1,47 @@
+#![cfg(feature = "bevy")]
+
+use bevy_reflect::{FromReflect, Reflect, ReflectRef, TypeRegistry};
+use glam::Vec3;
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn reflected_spline() {
+  let keys = [
+    Key::new(0., Vec3::ZERO, Interpolation::Linear),
+    Key::new(1., Vec3::ONE, Interpolation::Bezier(Vec3::X)),
+  ];
+  let spline = Spline::<f32, Vec3, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  let mut registry = TypeRegistry::default();
+  registry.register::<Spline<f32, Vec3, 2>>();
+  assert!(registry
+    .get(std::any::TypeId::of::<Spline<f32, Vec3, 2>>())
+    .is_some());
+
+  // round trip through reflection
+  let reflected = spline.clone_value();
+  assert_eq!(reflected.reflect_partial_eq(&spline), Some(true));
+  assert_eq!(
+    Spline::<f32, Vec3, 2>::from_reflect(&*reflected),
+    Some(spline.clone())
+  );
+
+  // keys and interpolation modes can be inspected field by field
+  let mut key = spline.keys()[1];
+  let ReflectRef::Struct(reflected) = key.reflect_ref() else {
+    panic!("keys should be reflected as structs");
+  };
+  assert_eq!(
+    reflected.field("t").unwrap().downcast_ref::<f32>(),
+    Some(&1.)
+  );
+
+  let ReflectRef::Enum(reflected) = key.interpolation.reflect_ref() else {
+    panic!("interpolation modes should be reflected as enums");
+  };
+  assert_eq!(reflected.variant_name(), "Bezier");
+
+  key.apply(&Key::new(2., Vec3::Y, Interpolation::<f32, Vec3>::Linear));
+  assert_eq!(key, Key::new(2., Vec3::Y, Interpolation::Linear));
+}
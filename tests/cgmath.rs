@@ -1,6 +1,7 @@
 #![cfg(feature = "cgmath")]
 
 use cgmath as cg;
+use heapless::Vec;
 use splines::{Interpolation, Key, Spline};
 
 #[test]
@@ -20,7 +21,7 @@ fn cgmath_vector_interpolation() {
 fn stroke_bezier_straight() {
   use float_cmp::approx_eq;
 
-  let keys = vec![
+  let keys = [
     Key::new(
       0.0,
       cg::Vector2::new(0., 1.),
@@ -32,7 +33,7 @@ fn stroke_bezier_straight() {
       Interpolation::StrokeBezier(cg::Vector2::new(5., 1.), cg::Vector2::new(5., 1.)),
     ),
   ];
-  let spline = Spline::from_vec(keys);
+  let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
 
   assert!(approx_eq!(f32, spline.clamped_sample(0.0).unwrap().y, 1.));
   assert!(approx_eq!(f32, spline.clamped_sample(1.0).unwrap().y, 1.));
@@ -41,3 +42,358 @@ fn stroke_bezier_straight() {
   assert!(approx_eq!(f32, spline.clamped_sample(4.0).unwrap().y, 1.));
   assert!(approx_eq!(f32, spline.clamped_sample(5.0).unwrap().y, 1.));
 }
+
+#[test]
+fn arc_length_straight_diagonal() {
+  use float_cmp::approx_eq;
+
+  let keys = [
+    Key::new(0., cg::Vector2::new(0., 0.), Interpolation::Cosine),
+    Key::new(1., cg::Vector2::new(1., 2.), Interpolation::Linear),
+    Key::new(3., cg::Vector2::new(3., 6.), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert!(approx_eq!(
+    f32,
+    spline.arc_length(16),
+    45f32.sqrt(),
+    epsilon = 1e-5
+  ));
+}
+
+#[test]
+fn nearest_on_circle() {
+  use cg::InnerSpace;
+  use std::f64::consts::FRAC_PI_4;
+
+  let keys = (0..=8).map(|i| {
+    let angle = i as f64 * FRAC_PI_4;
+    Key::new(
+      i as f64,
+      cg::Vector2::new(angle.cos(), angle.sin()),
+      Interpolation::TCB {
+        tension: 0.,
+        continuity: 0.,
+        bias: 0.,
+      },
+    )
+  });
+  let spline = Spline::<f64, _, 9>::from_iter(keys);
+
+  // by symmetry, the nearest point to a point on the axis of a key is the key itself
+  let (t, value) = spline.nearest(&cg::Vector2::new(0., -2.), 8).unwrap();
+  assert!((t - 6.).abs() < 1e-6, "{}", t);
+  assert!((value - cg::Vector2::new(0., -1.)).magnitude() < 1e-6);
+
+  // off keys, no dense sample is nearer than the returned point
+  let target = cg::Vector2::new(0.3, 0.9);
+  let (t, value) = spline.nearest(&target, 8).unwrap();
+  let distance = (value - target).magnitude();
+
+  for i in 0..=800 {
+    let sample = spline.clamped_sample(i as f64 / 100.).unwrap();
+    assert!(
+      distance <= (sample - target).magnitude() + 1e-9,
+      "{}: {}",
+      t,
+      i
+    );
+  }
+
+  assert!(Spline::<f64, cg::Vector2<f64>, 2>::from_vec(Vec::new())
+    .nearest(&target, 8)
+    .is_none());
+}
+
+#[test]
+fn quaternion_slerp() {
+  use cg::{Deg, InnerSpace, Rad, Rotation3};
+
+  let start = cg::Quaternion::from_angle_z(Deg(0.));
+  let end = cg::Quaternion::from_angle_z(Deg(170.));
+  let keys = [
+    Key::new(0., start, Interpolation::Linear),
+    Key::new(1., end, Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap();
+
+  assert!((mid.magnitude() - 1.).abs() < 1e-9);
+
+  let Rad(angle) = Rad::from(Deg(85.));
+  assert!((2. * mid.s.acos() - angle).abs() < 1e-9);
+  assert!((mid.v.z - (angle / 2.).sin()).abs() < 1e-9);
+}
+
+#[test]
+fn quaternion_slerp_shortest_path() {
+  use cg::{Deg, Rotation3};
+
+  // the same orientations with opposite signs
+  let start = cg::Quaternion::from_angle_z(Deg(10.));
+  let end = -cg::Quaternion::from_angle_z(Deg(30.));
+  let keys = [
+    Key::new(0., start, Interpolation::Linear),
+    Key::new(1., end, Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap();
+  let expected = cg::Quaternion::from_angle_z(Deg(20.));
+
+  assert!((mid - expected).s.abs() < 1e-9);
+  assert!((mid - expected).v.z.abs() < 1e-9);
+}
+
+#[test]
+fn quaternion_squad_angular_velocity() {
+  use cg::{Deg, InnerSpace, Rotation3};
+
+  let keys = [
+    Key::new(
+      0.,
+      cg::Quaternion::from_angle_x(Deg(-30.)),
+      Interpolation::CatmullRom,
+    ),
+    Key::new(
+      1.,
+      cg::Quaternion::from_angle_x(Deg(0.)),
+      Interpolation::CatmullRom,
+    ),
+    Key::new(
+      2.,
+      cg::Quaternion::from_angle_y(Deg(60.)),
+      Interpolation::CatmullRom,
+    ),
+    Key::new(
+      3.,
+      cg::Quaternion::from_angle_z(Deg(90.)),
+      Interpolation::CatmullRom,
+    ),
+    Key::new(
+      4.,
+      cg::Quaternion::from_angle_z(Deg(120.)),
+      Interpolation::default(),
+    ),
+  ];
+  let spline = Spline::<f64, _, 5>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  // angular velocity of the rotation between t and t + h
+  let h = 1e-4;
+  let angular_velocity = |t: f64| {
+    let a = spline.sample(t).unwrap();
+    let b = spline.sample(t + h).unwrap();
+    (a.conjugate() * b).v * (2. / h)
+  };
+
+  for t in [1.25, 1.5, 2.25, 2.75] {
+    assert!((spline.sample(t).unwrap().magnitude() - 1.).abs() < 1e-9);
+  }
+
+  assert!((spline.sample(2.).unwrap() - keys[2].value).magnitude() < 1e-12);
+
+  let before = angular_velocity(2. - h);
+  let after = angular_velocity(2.);
+  assert!((before - after).magnitude() < 1e-2 * after.magnitude());
+
+  // the derivative follows the squad, so the angular velocity it yields matches the finite differences
+  let derivative = |t: f64| spline.sample_with_derivative(t).unwrap();
+
+  for t in [1.25, 1.5, 2.25, 2.75] {
+    let (q, dq) = derivative(t);
+    let difference = (spline.sample(t + h).unwrap() - spline.sample(t - h).unwrap()) / (2. * h);
+    assert!((dq - difference).magnitude() < 1e-6, "{}", t);
+    assert!(((q.conjugate() * dq).v * 2. - angular_velocity(t)).magnitude() < 1e-3);
+  }
+
+  // and is continuous across the key
+  let velocity = |(q, dq): (cg::Quaternion<f64>, cg::Quaternion<f64>)| (q.conjugate() * dq).v * 2.;
+  let before = velocity(derivative(2. - 1e-9));
+  let after = velocity(derivative(2.));
+  assert!((before - after).magnitude() < 1e-6);
+}
+
+#[test]
+fn quaternion_squad_constant_rotation() {
+  use cg::{Deg, InnerSpace, Rotation3};
+
+  let keys: std::vec::Vec<_> = (0..5)
+    .map(|i| {
+      Key::new(
+        i as f64,
+        cg::Quaternion::from_angle_z(Deg(40. * i as f64)),
+        Interpolation::CatmullRom,
+      )
+    })
+    .collect();
+  let spline = Spline::<f64, _, 5>::from_vec(Vec::from_slice(&keys).unwrap());
+  let expected = cg::Quaternion::from_angle_z(Deg(90.));
+
+  assert!((spline.sample(2.25).unwrap() - expected).magnitude() < 1e-9);
+}
+
+#[test]
+fn circular_arc_quarter_circle() {
+  use cg::InnerSpace;
+
+  let point = |deg: f64| {
+    let rad = deg.to_radians();
+    cg::Vector2::new(2. * rad.cos() + 1., 2. * rad.sin() - 1.)
+  };
+  let keys = [
+    Key::new(0., point(0.), Interpolation::CircularArc),
+    Key::new(1., point(45.), Interpolation::CircularArc),
+    Key::new(2., point(90.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  for (t, deg) in [(0.5, 22.5), (1.25, 56.25), (1.5, 67.5)] {
+    let p = spline.sample(t).unwrap();
+    assert!((p - point(deg)).magnitude() < 1e-9);
+    assert!(((p - cg::Vector2::new(1., -1.)).magnitude() - 2.).abs() < 1e-9);
+  }
+
+  // the velocity is tangent to the circle, with a constant speed of 45° per unit of parameter
+  let (p, v) = spline.sample_with_derivative(1.5).unwrap();
+  assert!(v.dot(p - cg::Vector2::new(1., -1.)).abs() < 1e-9);
+  assert!((v.magnitude() - 2. * 45f64.to_radians()).abs() < 1e-9);
+}
+
+#[test]
+fn circular_arc_collinear() {
+  let keys = [
+    Key::new(0., cg::Vector2::new(0., 0.), Interpolation::CircularArc),
+    Key::new(1., cg::Vector2::new(1., 1.), Interpolation::CircularArc),
+    Key::new(2., cg::Vector2::new(2., 2.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(1.5), Some(cg::Vector2::new(1.5, 1.5)));
+}
+
+#[test]
+fn circular_arc_after_linear() {
+  use cg::InnerSpace;
+  use std::f64::consts::FRAC_PI_2;
+
+  // a straight line followed by a quarter of the unit circle, traced at the same speed
+  let keys = [
+    Key::new(0., cg::Vector2::new(-FRAC_PI_2, 1.), Interpolation::Linear),
+    Key::new(1., cg::Vector2::new(0., 1.), Interpolation::CircularArc),
+    Key::new(2., cg::Vector2::new(1., 0.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+  let eps = 1e-6;
+  let left = (spline.sample(1.).unwrap() - spline.sample(1. - eps).unwrap()) / eps;
+  let (_, right) = spline.sample_with_derivative(1.).unwrap();
+
+  assert!((left - right).magnitude() < 1e-6);
+  assert!((right - cg::Vector2::new(FRAC_PI_2, 0.)).magnitude() < 1e-9);
+  assert!((spline.sample(1.5).unwrap().magnitude() - 1.).abs() < 1e-9);
+
+  // the arc leaves the key in the direction of the line, whatever its speed
+  let keys = [
+    Key::new(0., cg::Vector2::new(-1., 0.), Interpolation::Linear),
+    Key::new(1., cg::Vector2::new(0., 0.), Interpolation::CircularArc),
+    Key::new(3., cg::Vector2::new(2., -3.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+  let left = (spline.sample(1.).unwrap() - spline.sample(1. - eps).unwrap()) / eps;
+  let right = (spline.sample(1. + eps).unwrap() - spline.sample(1.).unwrap()) / eps;
+
+  assert!((left.normalize() - right.normalize()).magnitude() < 1e-5);
+  assert!((spline.tangent(1.).unwrap() - cg::Vector2::new(1., 0.)).magnitude() < 1e-9);
+  assert!((spline.sample(3.).unwrap() - cg::Vector2::new(2., -3.)).magnitude() < 1e-9);
+}
+
+#[test]
+fn curvature_circle() {
+  // keys sampled every 15° on a circle of radius 2
+  let point = |deg: f64| {
+    let rad = deg.to_radians();
+    cg::Vector2::new(2. * rad.cos(), 2. * rad.sin())
+  };
+  let keys: std::vec::Vec<_> = (0..=24)
+    .map(|i| Key::new(i as f64, point(15. * i as f64), Interpolation::CatmullRom))
+    .collect();
+  let spline = Spline::<f64, _, 25>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  for i in 10..230 {
+    let kappa = spline.curvature(i as f64 / 10.).unwrap();
+    assert!((kappa - 0.5).abs() < 0.03, "curvature {} at {}", kappa, i);
+  }
+
+  // circular arcs have the exact curvature of their circle
+  let keys = [
+    Key::new(0., point(0.), Interpolation::CircularArc),
+    Key::new(1., point(45.), Interpolation::CircularArc),
+    Key::new(2., point(90.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  for t in [0., 0.5, 1., 1.75] {
+    assert!((spline.curvature(t).unwrap() - 0.5).abs() < 1e-6);
+  }
+}
+
+#[test]
+fn curvature_straight_line() {
+  let keys = [
+    Key::new(0., cg::Vector2::new(0., 0.), Interpolation::Smoothstep),
+    Key::new(1., cg::Vector2::new(1., 1.), Interpolation::Step(0.5)),
+    Key::new(2., cg::Vector2::new(2., 2.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert!(spline.curvature(0.3).unwrap().abs() < 1e-6);
+  assert_eq!(spline.curvature(0.), None);
+  assert_eq!(spline.curvature(1.5), None);
+  assert_eq!(spline.curvature(3.), None);
+}
+
+#[test]
+fn tangent_diagonal() {
+  use cg::InnerSpace;
+
+  let keys = [
+    Key::new(0., cg::Vector2::new(0., 0.), Interpolation::Smoothstep),
+    Key::new(1., cg::Vector2::new(3., 3.), Interpolation::Step(0.5)),
+    Key::new(2., cg::Vector2::new(6., 6.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+  let direction = cg::Vector2::new(1., 1.).normalize();
+
+  for t in [0.1, 0.5, 0.9] {
+    assert!((spline.tangent(t).unwrap() - direction).magnitude() < 1e-12);
+  }
+
+  // the speed is zero at the start of the smoothstep and on the step
+  assert_eq!(spline.tangent(0.), None);
+  assert_eq!(spline.tangent(1.5), None);
+  assert_eq!(spline.tangent(3.), None);
+}
+
+#[test]
+fn sample_with_speed_unit_line() {
+  // 3-4-5 segments travelled in 5 units of time each
+  let keys = [
+    Key::new(0., cg::Vector2::new(0., 0.), Interpolation::Linear),
+    Key::new(5., cg::Vector2::new(3., 4.), Interpolation::Linear),
+    Key::new(10., cg::Vector2::new(7., 1.), Interpolation::Step(0.5)),
+    Key::new(11., cg::Vector2::new(0., 0.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 4>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  for i in 0..20 {
+    let t = f64::from(i) / 2.;
+    let (value, speed) = spline.sample_with_speed(t).unwrap();
+
+    assert_eq!(Some(value), spline.sample(t));
+    assert!((speed - 1.).abs() < 1e-12);
+  }
+
+  assert_eq!(
+    spline.sample_with_speed(10.5).map(|(_, speed)| speed),
+    Some(0.)
+  );
+}
@@ -0,0 +1,114 @@
+//! Values implementing only the required methods of `Interpolate`, exercising the default implementations of the other
+//! methods.
+
+use heapless::Vec;
+use splines::{Interpolate, Interpolation, Key, Spline};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Scalar(f64);
+
+impl Interpolate<f64> for Scalar {
+  fn step(t: f64, threshold: f64, a: Self, b: Self) -> Self {
+    Scalar(f64::step(t, threshold, a.0, b.0))
+  }
+
+  fn lerp(t: f64, a: Self, b: Self) -> Self {
+    Scalar(f64::lerp(t, a.0, b.0))
+  }
+
+  fn cosine(t: f64, a: Self, b: Self) -> Self {
+    Scalar(f64::cosine(t, a.0, b.0))
+  }
+
+  fn cubic_hermite(t: f64, x: (f64, Self), a: (f64, Self), b: (f64, Self), y: (f64, Self)) -> Self {
+    Scalar(f64::cubic_hermite(
+      t,
+      (x.0, x.1 .0),
+      (a.0, a.1 .0),
+      (b.0, b.1 .0),
+      (y.0, y.1 .0),
+    ))
+  }
+
+  fn hermite(t: f64, a: (f64, Self), b: (f64, Self), m0: Self, m1: Self) -> Self {
+    Scalar(f64::hermite(t, (a.0, a.1 .0), (b.0, b.1 .0), m0.0, m1.0))
+  }
+
+  fn quadratic_bezier(t: f64, a: Self, u: Self, b: Self) -> Self {
+    Scalar(f64::quadratic_bezier(t, a.0, u.0, b.0))
+  }
+
+  fn cubic_bezier(t: f64, a: Self, u: Self, v: Self, b: Self) -> Self {
+    Scalar(f64::cubic_bezier(t, a.0, u.0, v.0, b.0))
+  }
+
+  fn cubic_bezier_mirrored(t: f64, a: Self, u: Self, v: Self, b: Self) -> Self {
+    Scalar(f64::cubic_bezier_mirrored(t, a.0, u.0, v.0, b.0))
+  }
+}
+
+fn spline(interpolation: Interpolation<f64, Scalar>) -> Spline<f64, Scalar, 4> {
+  let keys = [
+    Key::new(0., Scalar(0.), interpolation),
+    Key::new(1., Scalar(3.), interpolation),
+    Key::new(2., Scalar(1.), interpolation),
+    Key::new(3., Scalar(4.), Interpolation::default()),
+  ];
+
+  Spline::from_vec(Vec::from_slice(&keys).unwrap())
+}
+
+#[test]
+fn default_kochanek_bartels() {
+  // without arithmetic, tension, continuity and bias are ignored
+  let tcb = spline(Interpolation::TCB {
+    tension: 0.5,
+    continuity: -0.5,
+    bias: 0.25,
+  });
+  let catmull_rom = spline(Interpolation::CatmullRom);
+
+  for i in 0..10 {
+    let t = 1. + i as f64 / 10.;
+    assert!(tcb.sample(t).is_some());
+    assert_eq!(tcb.sample(t), catmull_rom.sample(t));
+  }
+}
+
+#[test]
+fn default_quintic_hermite() {
+  // without arithmetic, accelerations cannot be estimated
+  let quintic = spline(Interpolation::QuinticHermite);
+  let catmull_rom = spline(Interpolation::CatmullRom);
+
+  for i in 0..10 {
+    let t = 1. + i as f64 / 10.;
+    assert!(quintic.sample(t).is_some());
+    assert_eq!(quintic.sample(t), catmull_rom.sample(t));
+  }
+}
+
+#[test]
+fn default_smoothstep() {
+  let smoothstep = spline(Interpolation::Smoothstep);
+
+  for i in 0..=10 {
+    let t = i as f64 / 10.;
+    let eased_t = t * t * (3. - 2. * t);
+    let value = smoothstep.sample(t).unwrap().0;
+    assert!((value - 3. * eased_t).abs() < 1e-12);
+  }
+}
+
+#[test]
+fn default_smootherstep() {
+  // without arithmetic, the easing cannot be made quintic
+  let smootherstep = spline(Interpolation::Smootherstep);
+  let smoothstep = spline(Interpolation::Smoothstep);
+
+  for i in 0..30 {
+    let t = i as f64 / 10.;
+    assert!(smootherstep.sample(t).is_some());
+    assert_eq!(smootherstep.sample(t), smoothstep.sample(t));
+  }
+}
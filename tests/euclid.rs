@@ -0,0 +1,49 @@
+#![cfg(feature = "euclid")]
+
+use euclid::{Point2D, Vector2D};
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MyUnit;
+
+#[test]
+fn euclid_point_interpolation() {
+  let keys = [
+    Key::new(
+      0.,
+      Point2D::<f32, MyUnit>::new(0., 2.),
+      Interpolation::Linear,
+    ),
+    Key::new(
+      1.,
+      Point2D::<f32, MyUnit>::new(4., 6.),
+      Interpolation::default(),
+    ),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  let midpoint: Point2D<f32, MyUnit> = spline.sample(0.5).unwrap();
+  assert_eq!(midpoint, Point2D::new(2., 4.));
+}
+
+#[test]
+fn euclid_vector_interpolation() {
+  let keys = [
+    Key::new(
+      0.,
+      Vector2D::<f64, MyUnit>::new(0., 0.),
+      Interpolation::Cosine,
+    ),
+    Key::new(
+      2.,
+      Vector2D::<f64, MyUnit>::new(2., -4.),
+      Interpolation::default(),
+    ),
+  ];
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  let midpoint = spline.sample(1.).unwrap();
+  assert!((midpoint - Vector2D::new(1., -2.)).square_length() < 1e-18);
+  assert!((spline.arc_length(8) - 20f64.sqrt()).abs() < 1e-9);
+}
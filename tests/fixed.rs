@@ -0,0 +1,49 @@
+#![cfg(feature = "fixed")]
+
+use fixed::types::I16F16;
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn fixed_linear_interpolation() {
+  let keys = [
+    Key::new(
+      I16F16::from_num(0),
+      I16F16::from_num(-2),
+      Interpolation::Linear,
+    ),
+    Key::new(
+      I16F16::from_num(2),
+      I16F16::from_num(8),
+      Interpolation::default(),
+    ),
+  ];
+  let spline = Spline::<I16F16, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(
+    spline.sample(I16F16::from_num(1)),
+    Some(I16F16::from_num(3))
+  );
+  assert_eq!(
+    spline.sample(I16F16::from_num(2)),
+    Some(I16F16::from_num(8))
+  );
+  assert_eq!(spline.sample(I16F16::from_num(3)), None);
+}
+
+#[test]
+fn fixed_cosine_interpolation() {
+  let keys = [
+    Key::new(I16F16::ZERO, I16F16::ZERO, Interpolation::Cosine),
+    Key::new(I16F16::ONE, I16F16::from_num(10), Interpolation::default()),
+  ];
+  let spline = Spline::<I16F16, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  for i in 0..=16 {
+    let t = i as f64 / 16.;
+    let expected = 5. * (1. - (t * core::f64::consts::PI).cos());
+    let sampled = spline.sample(I16F16::from_num(t)).unwrap();
+
+    assert!((sampled.to_num::<f64>() - expected).abs() < 1e-3, "{}", t);
+  }
+}
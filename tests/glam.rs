@@ -0,0 +1,108 @@
+#![cfg(feature = "glam")]
+
+use glam::Quat;
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn quaternion_slerp() {
+  let start = Quat::from_rotation_y(0.);
+  let end = Quat::from_rotation_y(170f32.to_radians());
+  let keys = [
+    Key::new(0., start, Interpolation::Linear),
+    Key::new(1., end, Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap();
+
+  assert!(mid.is_normalized());
+  assert!(mid.abs_diff_eq(Quat::from_rotation_y(85f32.to_radians()), 1e-6));
+}
+
+#[test]
+fn quaternion_slerp_derivatives() {
+  use glam::DQuat;
+
+  let keys = [
+    Key::new(0., DQuat::from_rotation_y(0.), Interpolation::Linear),
+    Key::new(
+      1.,
+      DQuat::from_rotation_y(170f64.to_radians()),
+      Interpolation::Smoothstep,
+    ),
+    Key::new(2., DQuat::from_rotation_z(1.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+  let h = 1e-6;
+  let derivative = |t: f64| spline.sample_with_derivative(t).unwrap().1;
+
+  // the derivatives follow the slerp, not the chord between the keys
+  for t in [0.25, 0.5, 1.25, 1.5] {
+    let difference = (spline.sample(t + h).unwrap() - spline.sample(t - h).unwrap()) / (2. * h);
+    assert!(derivative(t).abs_diff_eq(difference, 1e-6), "{}", t);
+
+    let difference = (derivative(t + h) - derivative(t - h)) / (2. * h);
+    let second = spline.sample_second_derivative(t).unwrap();
+    assert!(second.abs_diff_eq(difference, 1e-4), "{}", t);
+  }
+
+  // quaternions are normalized when sampled, so only the keys of the derivative spline are meaningful
+  let derivative_spline = spline.derivative_spline::<6>();
+  let ts: std::vec::Vec<_> = derivative_spline.keys().iter().map(|key| key.t).collect();
+  assert_eq!(ts, [0., 1., 1., 2.]);
+  assert!(derivative_spline.keys()[0]
+    .value
+    .abs_diff_eq(derivative(0.), 1e-9));
+  assert!(derivative_spline.keys()[2]
+    .value
+    .abs_diff_eq(derivative(1.), 1e-9));
+}
+
+#[test]
+fn double_precision() {
+  use glam::{DQuat, DVec3};
+
+  let keys = [
+    Key::new(0., DVec3::ZERO, Interpolation::Linear),
+    Key::new(1., DVec3::new(1., 2., 3.), Interpolation::Cosine),
+    Key::new(2., DVec3::new(1e-9, 0., 0.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(DVec3::new(0.5, 1., 1.5)));
+  assert_eq!(spline.sample(2.), Some(DVec3::new(1e-9, 0., 0.)));
+  assert!(spline
+    .sample(1.5)
+    .unwrap()
+    .abs_diff_eq(DVec3::new(0.5 + 5e-10, 1., 1.5), 1e-12));
+
+  let start = DQuat::from_rotation_y(0.);
+  let end = DQuat::from_rotation_y(170f64.to_radians());
+  let keys = [
+    Key::new(0., start, Interpolation::Linear),
+    Key::new(1., end, Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap();
+
+  assert!(mid.is_normalized());
+  assert!(mid.abs_diff_eq(DQuat::from_rotation_y(85f64.to_radians()), 1e-12));
+}
+
+#[test]
+fn integer_vectors() {
+  use glam::IVec2;
+
+  let keys = [
+    Key::new(0., IVec2::new(0, 0), Interpolation::Linear),
+    Key::new(1., IVec2::new(2, 4), Interpolation::Cosine),
+    Key::new(2., IVec2::new(-3, 4), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(IVec2::new(1, 2)));
+  assert_eq!(spline.sample(0.6), Some(IVec2::new(1, 2)));
+  assert_eq!(spline.sample(0.9), Some(IVec2::new(2, 4)));
+  assert_eq!(spline.sample(1.5), Some(IVec2::new(-1, 4)));
+  assert_eq!(spline.sample(2.), Some(IVec2::new(-3, 4)));
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn half_precision() {
+  let keys = [
+    Key::new(0., f16::from_f32(1.), Interpolation::Linear),
+    Key::new(1., f16::from_f32(2.), Interpolation::CatmullRom),
+    Key::new(2., f16::from_f32(10.), Interpolation::CatmullRom),
+    Key::new(3., f16::from_f32(3.), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 4>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(f16::from_f32(1.5)));
+
+  // cubic modes are computed in single precision, rounding only the result
+  let single = spline.map_values(|value| value.to_f32());
+  let mid = spline.sample(1.5).unwrap();
+  assert_eq!(mid, f16::from_f32(single.sample(1.5).unwrap()));
+  assert_eq!(mid, f16::from_f32(6.5));
+}
+
+#[test]
+fn brain_floating_point() {
+  let keys = [
+    Key::new(0., bf16::from_f32(0.), Interpolation::Cosine),
+    Key::new(1., bf16::from_f32(300.), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap().to_f32();
+
+  assert!((mid - 150.).abs() <= 1.);
+}
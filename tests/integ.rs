@@ -1,17 +1,27 @@
-use splines::{spline::SampledWithKey, Interpolation, Key, Spline};
+use heapless::Vec;
+use splines::{spline::SampledWithKey, Interpolate, Interpolation, Key, Spline};
+
+// Spline holding copies of the given keys.
+fn from_keys<T, V, const N: usize>(keys: &[Key<T, V>]) -> Spline<T, V, N>
+where
+  T: Clone + PartialOrd,
+  V: Clone,
+{
+  Spline::from_vec(Vec::from_slice(keys).unwrap())
+}
 
 #[test]
 fn step_interpolation_f32() {
   let start = Key::new(0., 0., Interpolation::Step(0.));
   let end = Key::new(1., 10., Interpolation::default());
-  let spline = Spline::<f32, _>::from_vec(vec![start, end]);
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(10.));
   assert_eq!(spline.sample(0.1), Some(10.));
   assert_eq!(spline.sample(0.2), Some(10.));
   assert_eq!(spline.sample(0.5), Some(10.));
   assert_eq!(spline.sample(0.9), Some(10.));
-  assert_eq!(spline.sample(1.), None);
+  assert_eq!(spline.sample(1.), Some(10.));
   assert_eq!(spline.clamped_sample(1.), Some(10.));
   assert_eq!(
     spline.sample_with_key(0.2),
@@ -27,14 +37,14 @@ fn step_interpolation_f32() {
 fn step_interpolation_f64() {
   let start = Key::new(0., 0., Interpolation::Step(0.));
   let end = Key::new(1., 10., Interpolation::default());
-  let spline = Spline::<f64, _>::from_vec(vec![start, end]);
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(10.));
   assert_eq!(spline.sample(0.1), Some(10.));
   assert_eq!(spline.sample(0.2), Some(10.));
   assert_eq!(spline.sample(0.5), Some(10.));
   assert_eq!(spline.sample(0.9), Some(10.));
-  assert_eq!(spline.sample(1.), None);
+  assert_eq!(spline.sample(1.), Some(10.));
   assert_eq!(spline.clamped_sample(1.), Some(10.));
   assert_eq!(
     spline.sample_with_key(0.2),
@@ -50,14 +60,14 @@ fn step_interpolation_f64() {
 fn step_interpolation_0_5() {
   let start = Key::new(0., 0., Interpolation::Step(0.5));
   let end = Key::new(1., 10., Interpolation::default());
-  let spline = Spline::from_vec(vec![start, end]);
+  let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(0.));
   assert_eq!(spline.sample(0.1), Some(0.));
   assert_eq!(spline.sample(0.2), Some(0.));
   assert_eq!(spline.sample(0.5), Some(10.));
   assert_eq!(spline.sample(0.9), Some(10.));
-  assert_eq!(spline.sample(1.), None);
+  assert_eq!(spline.sample(1.), Some(10.));
   assert_eq!(spline.clamped_sample(1.), Some(10.));
 }
 
@@ -65,14 +75,14 @@ fn step_interpolation_0_5() {
 fn step_interpolation_0_75() {
   let start = Key::new(0., 0., Interpolation::Step(0.75));
   let end = Key::new(1., 10., Interpolation::default());
-  let spline = Spline::from_vec(vec![start, end]);
+  let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(0.));
   assert_eq!(spline.sample(0.1), Some(0.));
   assert_eq!(spline.sample(0.2), Some(0.));
   assert_eq!(spline.sample(0.5), Some(0.));
   assert_eq!(spline.sample(0.9), Some(10.));
-  assert_eq!(spline.sample(1.), None);
+  assert_eq!(spline.sample(1.), Some(10.));
   assert_eq!(spline.clamped_sample(1.), Some(10.));
 }
 
@@ -80,14 +90,14 @@ fn step_interpolation_0_75() {
 fn step_interpolation_1() {
   let start = Key::new(0., 0., Interpolation::Step(1.));
   let end = Key::new(1., 10., Interpolation::default());
-  let spline = Spline::from_vec(vec![start, end]);
+  let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(0.));
   assert_eq!(spline.sample(0.1), Some(0.));
   assert_eq!(spline.sample(0.2), Some(0.));
   assert_eq!(spline.sample(0.5), Some(0.));
   assert_eq!(spline.sample(0.9), Some(0.));
-  assert_eq!(spline.sample(1.), None);
+  assert_eq!(spline.sample(1.), Some(10.));
   assert_eq!(spline.clamped_sample(1.), Some(10.));
 }
 
@@ -95,14 +105,14 @@ fn step_interpolation_1() {
 fn linear_interpolation() {
   let start = Key::new(0., 0., Interpolation::Linear);
   let end = Key::new(1., 10., Interpolation::default());
-  let spline = Spline::from_vec(vec![start, end]);
+  let spline = Spline::<_, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(0.));
   assert_eq!(spline.sample(0.1), Some(1.));
   assert_eq!(spline.sample(0.2), Some(2.));
   assert_eq!(spline.sample(0.5), Some(5.));
   assert_eq!(spline.sample(0.9), Some(9.));
-  assert_eq!(spline.sample(1.), None);
+  assert_eq!(spline.sample(1.), Some(10.));
   assert_eq!(spline.clamped_sample(1.), Some(10.));
 }
 
@@ -114,7 +124,7 @@ fn linear_interpolation_several_keys() {
   let k3 = Key::new(3., 1., Interpolation::Linear);
   let k4 = Key::new(10., 2., Interpolation::Linear);
   let end = Key::new(11., 4., Interpolation::default());
-  let spline = Spline::from_vec(vec![start, k1, k2, k3, k4, end]);
+  let spline = Spline::<_, _, 6>::from_vec(Vec::from_slice(&[start, k1, k2, k3, k4, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(0.));
   assert_eq!(spline.sample(0.1), Some(0.5));
@@ -139,7 +149,7 @@ fn several_interpolations_several_keys() {
   let k3 = Key::new(3., 1., Interpolation::Linear);
   let k4 = Key::new(10., 2., Interpolation::Linear);
   let end = Key::new(11., 4., Interpolation::default());
-  let spline = Spline::from_vec(vec![start, k1, k2, k3, k4, end]);
+  let spline = Spline::<_, _, 6>::from_vec(Vec::from_slice(&[start, k1, k2, k3, k4, end]).unwrap());
 
   assert_eq!(spline.sample(0.), Some(0.));
   assert_eq!(spline.sample(0.1), Some(0.));
@@ -160,7 +170,7 @@ fn several_interpolations_several_keys() {
 
 #[test]
 fn add_key_empty() {
-  let mut spline: Spline<f32, f32> = Spline::from_vec(vec![]);
+  let mut spline: Spline<f32, f32, 1> = Spline::from_vec(Vec::new());
   spline.add(Key::new(0., 0., Interpolation::Linear));
 
   assert_eq!(spline.keys(), &[Key::new(0., 0., Interpolation::Linear)]);
@@ -175,7 +185,8 @@ fn add_key() {
   let k4 = Key::new(10., 2., Interpolation::Linear);
   let end = Key::new(11., 4., Interpolation::default());
   let new = Key::new(2.4, 40., Interpolation::Linear);
-  let mut spline = Spline::from_vec(vec![start, k1, k2.clone(), k3, k4, end]);
+  let mut spline =
+    Spline::<_, _, 7>::from_vec(Vec::from_slice(&[start, k1, k2, k3, k4, end]).unwrap());
 
   assert_eq!(spline.keys(), &[start, k1, k2, k3, k4, end]);
   spline.add(new);
@@ -184,7 +195,7 @@ fn add_key() {
 
 #[test]
 fn remove_element_empty() {
-  let mut spline: Spline<f32, f32> = Spline::from_vec(vec![]);
+  let mut spline: Spline<f32, f32, 1> = Spline::from_vec(Vec::new());
   let removed = spline.remove(0);
 
   assert_eq!(removed, None);
@@ -199,9 +210,2493 @@ fn remove_element() {
   let k3 = Key::new(3., 1., Interpolation::Linear);
   let k4 = Key::new(10., 2., Interpolation::Linear);
   let end = Key::new(11., 4., Interpolation::default());
-  let mut spline = Spline::from_vec(vec![start, k1, k2.clone(), k3, k4, end]);
+  let mut spline =
+    Spline::<_, _, 6>::from_vec(Vec::from_slice(&[start, k1, k2, k3, k4, end]).unwrap());
   let removed = spline.remove(2);
 
   assert_eq!(removed, Some(k2));
   assert_eq!(spline.len(), 5);
 }
+
+#[test]
+fn smoothstep_interpolations_ease_in_and_out() {
+  for mode in [Interpolation::Smoothstep, Interpolation::Smootherstep] {
+    let keys = [
+      Key::new(0., 2., mode),
+      Key::new(1., 6., mode),
+      Key::new(2., 6., Interpolation::default()),
+    ];
+    let spline = from_keys::<f64, f64, 3>(&keys);
+
+    assert_eq!(spline.sample(0.), Some(2.));
+    assert_eq!(spline.sample(0.5), Some(4.));
+    assert_eq!(spline.clamped_sample(1.), Some(6.));
+
+    let h = 1e-6;
+    let start = (spline.sample(h).unwrap() - spline.sample(0.).unwrap()) / h;
+    let end = (spline.sample(1.).unwrap() - spline.sample(1. - h).unwrap()) / h;
+
+    assert!(start.abs() < 1e-4, "{:?}: {}", mode, start);
+    assert!(end.abs() < 1e-4, "{:?}: {}", mode, end);
+  }
+}
+
+#[test]
+fn monotone_cubic_interpolation_does_not_overshoot() {
+  let keys = [
+    Key::new(0., 0., Interpolation::MonotoneCubic),
+    Key::new(1., 1., Interpolation::MonotoneCubic),
+    Key::new(2., 1.1, Interpolation::MonotoneCubic),
+    Key::new(3., 5., Interpolation::MonotoneCubic),
+    Key::new(4., 5.2, Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 5>(&keys);
+
+  for segment in keys.windows(2) {
+    let (k0, k1) = (segment[0], segment[1]);
+    let (min, max) = (k0.value.min(k1.value), k0.value.max(k1.value));
+
+    assert_eq!(spline.sample(k0.t), Some(k0.value));
+
+    for i in 0..100 {
+      let t = k0.t + (k1.t - k0.t) * i as f32 / 100.;
+      let value = spline.sample(t).unwrap();
+
+      assert!(
+        min <= value && value <= max,
+        "{} not in [{}, {}]",
+        value,
+        min,
+        max
+      );
+    }
+  }
+}
+
+#[test]
+fn akima_interpolation_is_robust_to_outliers() {
+  let spike = |mode| {
+    let keys = [
+      Key::new(0., 0., mode),
+      Key::new(1., 0., mode),
+      Key::new(2., 0., mode),
+      Key::new(3., 10., mode),
+      Key::new(4., 0., mode),
+      Key::new(5., 0., mode),
+      Key::new(6., 0., mode),
+    ];
+    from_keys::<f64, f64, 7>(&keys)
+  };
+  let akima = spike(Interpolation::Akima);
+  let catmull_rom = spike(Interpolation::CatmullRom);
+
+  let mut akima_error = 0f64;
+  let mut catmull_rom_error = 0f64;
+
+  for i in (10..20).chain(40..50) {
+    let t = i as f64 / 10.;
+    akima_error = akima_error.max(akima.sample(t).unwrap().abs());
+    catmull_rom_error = catmull_rom_error.max(catmull_rom.sample(t).unwrap().abs());
+  }
+
+  assert_eq!(akima_error, 0.);
+  assert!(catmull_rom_error > 0.5);
+
+  // the spike itself is still interpolated smoothly
+  for i in 20..40 {
+    let t = i as f64 / 10.;
+    let value = akima.sample(t).unwrap();
+    assert!((0. ..=10.).contains(&value), "{}: {}", t, value);
+  }
+
+  // two keys are enough
+  let keys = [
+    Key::new(0., 0., Interpolation::Akima),
+    Key::new(1., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline.sample(0.25), Some(1.));
+}
+
+#[test]
+fn tcb_interpolation_zero_is_catmull_rom() {
+  use float_cmp::approx_eq;
+
+  let tcb = Interpolation::TCB {
+    tension: 0.,
+    continuity: 0.,
+    bias: 0.,
+  };
+  let keys = [(0., 0.), (1., 5.), (3., 2.), (4., 10.)];
+  let cr = Spline::<f32, f32, 4>::from_vec(
+    keys
+      .iter()
+      .map(|&(t, v)| Key::new(t, v, Interpolation::CatmullRom))
+      .collect(),
+  );
+  let kb =
+    Spline::<f32, f32, 4>::from_vec(keys.iter().map(|&(t, v)| Key::new(t, v, tcb)).collect());
+
+  // only the middle segment is defined for Catmull-Rom
+  for i in 0..20 {
+    let t = 1. + i as f32 / 10.;
+
+    assert!(approx_eq!(
+      f32,
+      kb.sample(t).unwrap(),
+      cr.sample(t).unwrap(),
+      epsilon = 1e-5
+    ));
+  }
+}
+
+#[test]
+fn tcb_interpolation_high_tension_holds() {
+  use float_cmp::approx_eq;
+
+  let hold = Interpolation::TCB {
+    tension: 1.,
+    continuity: 0.,
+    bias: 0.,
+  };
+  let keys = [
+    Key::new(0., 0., hold),
+    Key::new(1., 10., hold),
+    Key::new(2., 0., hold),
+  ];
+  let spline = from_keys::<f32, f32, 3>(&keys);
+
+  // zero tangents yield a smoothstep-shaped segment
+  for i in 0..=10 {
+    let t = i as f32 / 10.;
+    let expected = 10. * (3. * t * t - 2. * t * t * t);
+
+    assert!(approx_eq!(
+      f32,
+      spline.sample(t).unwrap(),
+      expected,
+      epsilon = 1e-4
+    ));
+  }
+
+  // the curve flattens around the key
+  assert!(approx_eq!(
+    f32,
+    spline.sample(0.99).unwrap(),
+    10.,
+    epsilon = 1e-2
+  ));
+  assert!(approx_eq!(
+    f32,
+    spline.sample(1.01).unwrap(),
+    10.,
+    epsilon = 1e-2
+  ));
+}
+
+#[test]
+fn quintic_hermite_interpolation_is_c2() {
+  let keys = [
+    Key::new(0., 0., Interpolation::QuinticHermite),
+    Key::new(1., 5., Interpolation::QuinticHermite),
+    Key::new(3., 2., Interpolation::QuinticHermite),
+    Key::new(4., 10., Interpolation::QuinticHermite),
+    Key::new(6., 7., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+
+  for key in spline.keys() {
+    assert_eq!(spline.clamped_sample(key.t), Some(key.value));
+  }
+
+  // velocity and acceleration, estimated with finite differences on one side of t
+  let h = 1e-4;
+  let velocity = |t: f64, side: f64| {
+    (spline.clamped_sample(t + side * h).unwrap() - spline.clamped_sample(t).unwrap()) * side / h
+  };
+  let acceleration = |t: f64, side: f64| {
+    (spline.clamped_sample(t + 2. * side * h).unwrap()
+      - 2. * spline.clamped_sample(t + side * h).unwrap()
+      + spline.clamped_sample(t).unwrap())
+      / (h * h)
+  };
+
+  for &t in &[1., 3., 4.] {
+    let (v0, v1) = (velocity(t, -1.), velocity(t, 1.));
+    let (a0, a1) = (acceleration(t, -1.), acceleration(t, 1.));
+
+    assert!(
+      (v0 - v1).abs() < 1e-2,
+      "velocity at {}: {} != {}",
+      t,
+      v0,
+      v1
+    );
+    assert!(
+      (a0 - a1).abs() < 1e-1,
+      "acceleration at {}: {} != {}",
+      t,
+      a0,
+      a1
+    );
+  }
+
+  // two keys are enough
+  let keys = [
+    Key::new(0., 0., Interpolation::QuinticHermite),
+    Key::new(1., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline.sample(0.5), Some(2.));
+}
+
+#[test]
+fn hermite_interpolation() {
+  let hermite = |in_tangent, out_tangent| Interpolation::Hermite {
+    in_tangent,
+    out_tangent,
+  };
+  let keys = [
+    Key::new(0., 0., hermite(0., 4.)),
+    Key::new(2., 10., hermite(-1., 3.)),
+    Key::new(3., 5., Interpolation::Linear),
+    Key::new(4., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  // the curve passes through the keys with the given tangents, and has a corner on the second key
+  assert_eq!(spline.sample_with_derivative(0.), Some((0., 4.)));
+  assert_eq!(
+    spline
+      .sample_with_derivative(1.999999)
+      .map(|(_, d)| d.round()),
+    Some(-1.)
+  );
+  assert_eq!(spline.sample_with_derivative(2.), Some((10., 3.)));
+
+  // the next key doesn’t use a Hermite interpolation, so the out tangent is used on both ends of the segment
+  let (value, derivative) = spline.sample_with_derivative(2.999999).unwrap();
+  assert!((value - 5.).abs() < 1e-5);
+  assert!((derivative - 3.).abs() < 1e-4);
+
+  // the reversed spline has negated tangents
+  let reversed = spline.reverse();
+  for i in 0..=40 {
+    let t = i as f64 * 0.1;
+    let (a, b) = (spline.sample(t).unwrap(), reversed.sample(4. - t).unwrap());
+    assert!((a - b).abs() < 1e-9, "t = {}: {} != {}", t, a, b);
+  }
+
+  // tangents are mapped along with the values
+  let doubled = spline.map_values(|v| v * 2.);
+  assert_eq!(doubled.keys()[1].interpolation, hermite(-2., 6.));
+  assert_eq!(doubled.sample(1.), spline.sample(1.).map(|v| v * 2.));
+}
+
+#[test]
+fn exponential() {
+  let keys = [
+    Key::new(0., 1., Interpolation::Exponential),
+    Key::new(2., 100., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert!((spline.sample(1.).unwrap() - 10.).abs() < 1e-12);
+  assert!((spline.sample(0.5).unwrap() - 10f64.sqrt()).abs() < 1e-12);
+
+  let (value, derivative) = spline.sample_with_derivative(1.).unwrap();
+  assert!((derivative - value * 100f64.ln() / 2.).abs() < 1e-12);
+}
+
+#[test]
+fn circular_arc_scalar() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CircularArc),
+    Key::new(1., 10., Interpolation::CircularArc),
+    Key::new(2., 30., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.sample(0.5), Some(5.));
+  assert_eq!(spline.sample(1.5), Some(20.));
+}
+
+#[test]
+fn catmull_rom_endpoints() {
+  use splines::interpolation::CatmullRomEndpoints;
+
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 2., Interpolation::CatmullRom),
+    Key::new(2., 1., Interpolation::CatmullRom),
+    Key::new(3., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+  let eps = 1e-6;
+  let tangent = |t: f64, endpoints| {
+    (spline.sample_with_endpoints(t + eps, endpoints).unwrap()
+      - spline.sample_with_endpoints(t - eps, endpoints).unwrap())
+      / (2. * eps)
+  };
+
+  // the end segments cannot be sampled without picking a behavior
+  assert_eq!(spline.sample(0.5), None);
+  assert_eq!(
+    spline.sample_with_endpoints(0., CatmullRomEndpoints::default()),
+    Some(0.)
+  );
+  assert_eq!(
+    spline.sample_with_endpoints(3., CatmullRomEndpoints::default()),
+    Some(0.)
+  );
+
+  // inner segments are not affected
+  assert_eq!(
+    spline.sample_with_endpoints(1.5, CatmullRomEndpoints::Natural),
+    spline.sample(1.5)
+  );
+
+  // slope of the first segment
+  assert!((tangent(eps, CatmullRomEndpoints::Clamped) - 2.).abs() < 1e-4);
+
+  // no curvature at the first key
+  assert!((tangent(eps, CatmullRomEndpoints::Natural) - 2.75).abs() < 1e-4);
+
+  // the key before the last one precedes the first key, and the seam is smooth
+  assert!((tangent(eps, CatmullRomEndpoints::Cyclic) - 0.5).abs() < 1e-4);
+  assert!((tangent(3. - eps, CatmullRomEndpoints::Cyclic) - 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn auto_bezier() {
+  let points = [(0., 0.), (1., 10.), (3., 5.), (4., 20.), (6., 0.)];
+  let spline = Spline::<f64, f64, 5>::auto_bezier(&points, 1.);
+
+  assert_eq!(spline.len(), 5);
+  assert!(spline
+    .keys()
+    .iter()
+    .zip(&points)
+    .all(|(key, &(t, value))| key.t == t && key.value == value));
+
+  // C1 continuity at interior keys, despite uneven spacing
+  for &(t, value) in &points[1..4] {
+    let (left, left_derivative) = spline.sample_with_derivative(t - 1e-9).unwrap();
+    let (right, right_derivative) = spline.sample_with_derivative(t).unwrap();
+
+    assert!((left - value).abs() < 1e-6);
+    assert_eq!(right, value);
+    assert!(
+      (left_derivative - right_derivative).abs() < 1e-6,
+      "derivatives at {}: {} and {}",
+      t,
+      left_derivative,
+      right_derivative
+    );
+  }
+
+  // interior segments match Catmull-Rom ones
+  let catmull_rom = Spline::<f64, f64, 5>::from_iter(
+    points
+      .iter()
+      .map(|&(t, value)| Key::new(t, value, Interpolation::CatmullRom)),
+  );
+
+  for i in 0..=100 {
+    let t = 1. + 2. * i as f64 / 100.;
+    assert!((spline.sample(t).unwrap() - catmull_rom.sample(t).unwrap()).abs() < 1e-9);
+  }
+
+  // without smoothness, segments are straight
+  let straight = Spline::<f64, f64, 5>::auto_bezier(&points, 0.);
+  assert!((straight.sample(2.).unwrap() - 7.5).abs() < 1e-9);
+}
+
+#[test]
+fn array_interpolation() {
+  let keys = [
+    Key::new(0., [0., 0.], Interpolation::Linear),
+    Key::new(1., [2., 4.], Interpolation::Cosine),
+    Key::new(2., [4., 0.], Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, [f64; 2], 3>(&keys);
+
+  assert_eq!(spline.sample(0.5), Some([1., 2.]));
+  assert_eq!(
+    spline.sample(1.5),
+    Some([f64::cosine(0.5, 2., 4.), f64::cosine(0.5, 4., 0.)])
+  );
+  assert_eq!(spline.bounding_box(1), Some(([0., 0.], [4., 4.])));
+
+  let keys = [
+    Key::new(0., [0.; 5], Interpolation::CatmullRom),
+    Key::new(1., [1., 2., 3., 4., 5.], Interpolation::CatmullRom),
+    Key::new(2., [2., 4., 6., 8., 10.], Interpolation::CatmullRom),
+    Key::new(3., [3., 6., 9., 12., 15.], Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, [f32; 5], 4>(&keys);
+
+  assert_eq!(spline.sample(1.5), Some([1.5, 3., 4.5, 6., 7.5]));
+}
+
+#[test]
+fn tuple_interpolation() {
+  let keys = [
+    Key::new(0., (0., 10.), Interpolation::Linear),
+    Key::new(1., (4., 0.), Interpolation::Step(0.5)),
+    Key::new(2., (8., 20.), Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, (f32, f32), 3>(&keys);
+
+  assert_eq!(spline.sample(0.25), Some((1., 7.5)));
+  assert_eq!(spline.sample(0.5), Some((2., 5.)));
+  assert_eq!(spline.sample(1.25), Some((4., 0.)));
+  assert_eq!(spline.sample(1.75), Some((8., 20.)));
+  assert_eq!(spline.bounding_box(1), Some(((0., 0.), (8., 20.))));
+}
+
+#[test]
+fn sample_upper_domain_boundary() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Step(0.5)),
+    Key::new(2., 20., Interpolation::CatmullRom),
+    Key::new(3., 30., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  // the last key is sampled even though its segment can’t be interpolated
+  assert_eq!(spline.sample(3.), Some(30.));
+  assert_eq!(
+    spline.sample_with_key(3.),
+    Some(SampledWithKey { value: 30., key: 3 })
+  );
+  assert_eq!(spline.cursor().sample(3.), Some(30.));
+  assert_eq!(spline.sample(3.1), None);
+
+  // interior keys start their own segment
+  assert_eq!(
+    spline.sample_with_key(1.),
+    Some(SampledWithKey { value: 10., key: 1 })
+  );
+  assert_eq!(spline.sample(2.), None);
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.sample_with_derivative(1.), Some((10., -10.)));
+  assert_eq!(spline.sample_with_derivative(2.), Some((0., -10.)));
+}
+
+#[test]
+fn sample_duplicate_keys() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(0.5, 5., Interpolation::Linear),
+    Key::new(0.5, 10., Interpolation::Linear),
+    Key::new(0.5, 20., Interpolation::Linear),
+    Key::new(1., 30., Interpolation::Linear),
+    Key::new(1., 40., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 6>(&keys);
+
+  // the jump is right-continuous
+  assert_eq!(spline.sample(0.25), Some(2.5));
+  assert_eq!(
+    spline.sample_with_key(0.5),
+    Some(SampledWithKey { value: 20., key: 3 })
+  );
+  assert_eq!(spline.sample(0.75), Some(25.));
+  assert_eq!(spline.cursor().sample(0.5), Some(20.));
+  assert_eq!(spline.sample_with_derivative(0.5), Some((20., 20.)));
+
+  // a jump on the last key yields the last value, without derivative
+  assert_eq!(spline.sample(1.), Some(40.));
+  assert_eq!(spline.clamped_sample(1.), Some(40.));
+  assert_eq!(spline.sample_with_derivative(1.), None);
+}
+
+#[test]
+fn clamped_sample_with_key_out_of_domain() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, _, 3>(&keys);
+
+  assert_eq!(
+    spline.clamped_sample_with_key(-1.),
+    Some(SampledWithKey { value: 0., key: 0 })
+  );
+  assert_eq!(
+    spline.clamped_sample_with_key(1.5),
+    Some(SampledWithKey { value: 15., key: 1 })
+  );
+  assert_eq!(
+    spline.clamped_sample_with_key(3.),
+    Some(SampledWithKey { value: 20., key: 2 })
+  );
+}
+
+#[test]
+fn try_sample() {
+  use splines::spline::SampleError;
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::CatmullRom),
+    Key::new(2., 2., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.try_sample(0.5), Ok(2.5));
+  assert_eq!(
+    spline.try_sample(-1.),
+    Err(SampleError::BelowDomain { min: 0., max: 2. })
+  );
+  assert_eq!(spline.try_sample(2.), Ok(2.));
+  assert_eq!(
+    spline.try_sample(2.5),
+    Err(SampleError::AboveDomain { min: 0., max: 2. })
+  );
+  assert_eq!(
+    spline.try_sample(1.5),
+    Err(SampleError::NotEnoughKeys { key: 1 })
+  );
+  assert_eq!(
+    Spline::<f64, f64, 3>::from_vec(Vec::new()).try_sample(0.),
+    Err(SampleError::Empty)
+  );
+
+  assert_eq!(
+    SampleError::BelowDomain { min: 0., max: 2. }.to_string(),
+    "sampling parameter below the spline domain [0, 2]"
+  );
+}
+
+#[test]
+fn sample_many_keys() {
+  const N: usize = 4096;
+
+  // xorshift, to get reproducible pseudo-random numbers in [0; 1)
+  let mut state = 0x2545_f491_u32;
+  let mut random = move || {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    (state >> 8) as f64 / (1 << 24) as f64
+  };
+
+  let keys: Vec<_, N> = (0..N)
+    .map(|i| {
+      Key::new(
+        i as f64 + random() * 0.5,
+        random() * 100.,
+        Interpolation::Linear,
+      )
+    })
+    .collect();
+  let spline = Spline::from_vec(keys.clone());
+
+  for _ in 0..10_000 {
+    let t = random() * (N + 1) as f64 - 1.;
+    let expected = keys
+      .windows(2)
+      .find(|w| w[0].t <= t && t < w[1].t)
+      .map(|w| {
+        let nt = (t - w[0].t) / (w[1].t - w[0].t);
+        w[0].value * (1. - nt) + w[1].value * nt
+      });
+
+    assert_eq!(spline.sample(t), expected);
+  }
+}
+
+#[test]
+fn cursor_sampling() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 5., Interpolation::CatmullRom),
+    Key::new(2., 0., Interpolation::Cosine),
+    Key::new(3., 1., Interpolation::CatmullRom),
+    Key::new(10., 2., Interpolation::Linear),
+    Key::new(11., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 6>(&keys);
+
+  // sequential access
+  let mut cursor = spline.cursor();
+  for i in -10..130 {
+    let t = i as f32 / 10.;
+    assert_eq!(cursor.sample(t), spline.sample(t));
+  }
+
+  // random access
+  let mut cursor = spline.cursor();
+  for i in [70, 3, 115, 20, 21, -5, 110, 0, 99, 100, 25, 15] {
+    let t = i as f32 / 10.;
+    assert_eq!(cursor.sample(t), spline.sample(t));
+  }
+}
+
+#[test]
+fn sample_many() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::CatmullRom),
+    Key::new(2., 2., Interpolation::Cosine),
+    Key::new(3., 8., Interpolation::Step(0.5)),
+    Key::new(4., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+
+  let sorted: std::vec::Vec<f64> = (-5..50).map(|i| i as f64 / 10.).collect();
+  let mut shuffled = sorted.clone();
+  let mut state = 0x2545_f491_u32;
+  for i in (1..shuffled.len()).rev() {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    shuffled.swap(i, state as usize % (i + 1));
+  }
+
+  for ts in [sorted, shuffled] {
+    let mut out = vec![None; ts.len()];
+    spline.sample_many(&ts, &mut out);
+
+    for (&t, &sampled) in ts.iter().zip(&out) {
+      assert_eq!(sampled, spline.sample(t), "t = {}", t);
+    }
+  }
+}
+
+#[test]
+#[should_panic]
+fn sample_many_mismatched_lengths() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+  spline.sample_many(&[0., 0.5], &mut [None]);
+}
+
+#[test]
+fn normalized_sample() {
+  let keys = [
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(3., 5., Interpolation::Cosine),
+    Key::new(6., 2., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.normalized_sample(0.5), spline.sample(4.));
+  assert_eq!(spline.normalized_sample(0.), Some(0.));
+  assert_eq!(spline.normalized_sample(1.), Some(2.));
+  assert_eq!(spline.normalized_sample(1.5), None);
+  assert_eq!(spline.normalized_sample(-0.5), None);
+  assert_eq!(spline.clamped_normalized_sample(1.), Some(2.));
+  assert_eq!(spline.clamped_normalized_sample(-0.5), Some(0.));
+
+  // all keys at the same sampling parameter
+  let keys = [
+    Key::new(1., 3., Interpolation::Linear),
+    Key::new(1., 3., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline.clamped_normalized_sample(0.5), Some(3.));
+  assert_eq!(
+    Spline::<f64, f64, 2>::from_vec(Vec::new()).normalized_sample(0.5),
+    None
+  );
+}
+
+#[test]
+fn sample_looped() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 0., Interpolation::CatmullRom),
+    Key::new(3., -1., Interpolation::CatmullRom),
+    Key::new(4., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+  let looped = |t| spline.sample_looped(t).unwrap();
+
+  assert_eq!(looped(0.), 0.);
+  assert_eq!(looped(4.), 0.);
+  assert_eq!(looped(1.5), spline.sample(1.5).unwrap());
+  assert!((looped(4.25) - looped(0.25)).abs() < 1e-12);
+  assert!((looped(8.25) - looped(0.25)).abs() < 1e-12);
+  assert!((looped(-0.75) - looped(3.25)).abs() < 1e-12);
+
+  // the seam is continuous, and so is the derivative
+  let eps = 1e-6;
+  let before = (looped(4.) - looped(4. - eps)) / eps;
+  let after = (looped(4. + eps) - looped(4.)) / eps;
+  assert!((looped(4. + eps) - looped(4. - eps)).abs() < 1e-5);
+  assert!((before - after).abs() < 1e-4);
+
+  assert_eq!(spline.sample_looped(f64::NAN), None);
+  assert_eq!(spline.sample_looped(f64::INFINITY), None);
+
+  let spline = from_keys::<f64, f64, 1>(&keys[..1]);
+  assert_eq!(spline.sample_looped(0.), None);
+}
+
+#[test]
+fn sample_with_mode() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 10., Interpolation::Bezier(20.)),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  let linear = spline
+    .sample_with_mode(0.25, Interpolation::Linear)
+    .unwrap();
+  let cosine = spline
+    .sample_with_mode(0.25, Interpolation::Cosine)
+    .unwrap();
+  assert_eq!(linear, 2.5);
+  assert!((cosine - 10. * (1. - (0.25 * std::f64::consts::PI).cos()) / 2.).abs() < 1e-12);
+  assert!(cosine < linear);
+
+  assert_eq!(
+    spline.sample_with_mode(1.5, Interpolation::Linear),
+    Some(5.)
+  );
+  assert_eq!(spline.sample_with_mode(2., Interpolation::Linear), Some(0.));
+  assert_eq!(spline.sample_with_mode(2.5, Interpolation::Linear), None);
+  assert_eq!(
+    spline.sample_with_mode(1.5, Interpolation::CatmullRom),
+    None
+  );
+  assert_eq!(
+    spline.sample_with_mode(1.5, Interpolation::Bezier(0.)),
+    None
+  );
+
+  // the stored modes are left untouched
+  assert_eq!(spline.sample(0.25), Some(0.));
+}
+
+#[test]
+fn sample_with_local() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(3., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.sample_with_local(0.5), Some((5., 0, 0.5)));
+
+  let (value, key, u) = spline.sample_with_local(2.).unwrap();
+  assert!((value - 5.).abs() < 1e-12);
+  assert_eq!(key, 1);
+  assert!((u - 0.5).abs() < 1e-12);
+
+  assert_eq!(spline.sample_with_local(1.), Some((10., 1, 0.)));
+  assert_eq!(spline.sample_with_local(3.), Some((0., 1, 1.)));
+  assert_eq!(spline.sample_with_local(3.5), None);
+}
+
+#[test]
+fn sample_range() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  let samples = spline.sample_range::<9>(0., 2., 9);
+  assert_eq!(samples.len(), 9);
+  assert_eq!(samples.first(), Some(&0.));
+  assert_eq!(samples[2], 5.);
+  assert_eq!(samples[4], 10.);
+  assert_eq!(samples.last(), Some(&0.));
+
+  // parameters outside of the domain are clamped
+  let samples = spline.sample_range::<5>(-1., 3., 5);
+  assert_eq!(samples, [0., 0., 10., 0., 0.]);
+
+  assert_eq!(spline.sample_range::<1>(0.5, 3., 1), [5.]);
+  assert!(Spline::<f64, f64, 3>::default()
+    .sample_range::<4>(0., 1., 4)
+    .is_empty());
+}
+
+#[test]
+fn iter_sampled() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+  let samples: std::vec::Vec<_> = spline.iter_sampled(5).collect();
+
+  assert_eq!(samples.len(), 5);
+  assert_eq!(samples.first(), Some(&(0., 0.)));
+  assert_eq!(samples.last(), Some(&(2., 0.)));
+  assert_eq!(samples[1], (0.5, 5.));
+  assert_eq!(samples[2], (1., 10.));
+
+  assert_eq!(
+    spline.iter_sampled(1).collect::<std::vec::Vec<_>>(),
+    [(0., 0.)]
+  );
+  assert_eq!(spline.iter_sampled(0).count(), 0);
+  assert_eq!(Spline::<f64, f64, 3>::default().iter_sampled(5).count(), 0);
+}
+
+#[test]
+fn segment_at() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(3., 20., Interpolation::Linear),
+    Key::new(4., 30., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  assert_eq!(spline.segment_at(0.), Some(0));
+  assert_eq!(spline.segment_at(2.5), Some(1));
+  assert_eq!(spline.segment_at(3.), Some(2));
+  assert_eq!(spline.segment_at(4.), Some(2));
+  assert_eq!(spline.segment_at(-0.1), None);
+  assert_eq!(spline.segment_at(4.1), None);
+}
+
+#[test]
+fn duration_sampling_parameter() {
+  use std::time::Duration;
+
+  let keys = [
+    Key::new(Duration::ZERO, 0., Interpolation::Linear),
+    Key::new(Duration::from_secs(1), 10., Interpolation::Cosine),
+    Key::new(Duration::from_secs(3), 20., Interpolation::default()),
+  ];
+  let spline = from_keys::<Duration, f64, 3>(&keys);
+
+  assert_eq!(spline.sample(Duration::from_millis(500)), Some(5.));
+  assert!((spline.sample(Duration::from_secs(2)).unwrap() - 15.).abs() < 1e-9);
+  assert_eq!(spline.clamped_sample(Duration::from_secs(4)), Some(20.));
+  assert_eq!(spline.sample(Duration::from_secs(4)), None);
+}
+
+#[test]
+fn sample_with_derivative() {
+  use float_cmp::approx_eq;
+
+  let tcb = Interpolation::TCB {
+    tension: 0.5,
+    continuity: 0.,
+    bias: 0.2,
+  };
+  let modes = [
+    Interpolation::Linear,
+    Interpolation::Cosine,
+    Interpolation::Smoothstep,
+    Interpolation::Smootherstep,
+    Interpolation::CatmullRom,
+    Interpolation::MonotoneCubic,
+    Interpolation::Akima,
+    Interpolation::QuinticHermite,
+    tcb,
+    Interpolation::Bezier(3.),
+    Interpolation::StrokeBezier(2., 4.),
+    Interpolation::Hermite {
+      in_tangent: -2.,
+      out_tangent: 3.,
+    },
+  ];
+
+  for mode in modes {
+    let keys = [
+      Key::new(0., 0., mode),
+      Key::new(1., 5., mode),
+      Key::new(3., 2., mode),
+      Key::new(4., 10., mode),
+    ];
+    let spline = from_keys::<f64, f64, 4>(&keys);
+
+    for i in 1..20 {
+      let t = 1. + i as f64 / 10.;
+      let (value, derivative) = spline.sample_with_derivative(t).unwrap();
+      let dt = 1e-6;
+      let expected = (spline.sample(t + dt).unwrap() - spline.sample(t - dt).unwrap()) / (2. * dt);
+
+      assert_eq!(Some(value), spline.sample(t));
+      assert!(
+        approx_eq!(f64, derivative, expected, epsilon = 1e-5),
+        "{:?} at {}: {} != {}",
+        mode,
+        t,
+        derivative,
+        expected
+      );
+    }
+  }
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 5., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline.sample_with_derivative(0.7), Some((5., 0.)));
+  assert_eq!(spline.sample_with_derivative(1.5), None);
+}
+
+#[test]
+fn sample_second_derivative() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 3., Interpolation::CatmullRom),
+    Key::new(3., -2., Interpolation::Bezier(4.)),
+    Key::new(4., 5., Interpolation::Linear),
+    Key::new(6., 1., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+
+  // central finite difference of the sampled values
+  let h = 1e-4;
+  let estimate = |t: f64| {
+    (spline.sample(t + h).unwrap() - 2. * spline.sample(t).unwrap() + spline.sample(t - h).unwrap())
+      / (h * h)
+  };
+
+  for &t in &[1.2, 2., 2.9, 3.1, 3.5] {
+    let second = spline.sample_second_derivative(t).unwrap();
+    assert!(
+      (second - estimate(t)).abs() < 1e-3,
+      "t = {}: {} vs {}",
+      t,
+      second,
+      estimate(t)
+    );
+  }
+
+  // the second derivative varies linearly along cubic segments
+  let at = |t| spline.sample_second_derivative(t).unwrap();
+  assert!((at(2.) - (at(1.5) + at(2.5)) / 2.).abs() < 1e-9);
+
+  assert_eq!(spline.sample_second_derivative(5.), Some(0.));
+  assert_eq!(spline.sample_second_derivative(0.5), None);
+}
+
+#[test]
+fn curvature_scalar() {
+  use float_cmp::approx_eq;
+
+  let tcb = Interpolation::TCB {
+    tension: 0.5,
+    continuity: 0.,
+    bias: 0.2,
+  };
+  let modes = [
+    Interpolation::Linear,
+    Interpolation::Cosine,
+    Interpolation::Smoothstep,
+    Interpolation::Smootherstep,
+    Interpolation::CatmullRom,
+    Interpolation::MonotoneCubic,
+    Interpolation::Akima,
+    Interpolation::QuinticHermite,
+    tcb,
+    Interpolation::Bezier(3.),
+    Interpolation::StrokeBezier(2., 4.),
+    Interpolation::Hermite {
+      in_tangent: -2.,
+      out_tangent: 3.,
+    },
+  ];
+
+  // the curvature of scalars is their second derivative
+  for mode in modes {
+    let keys = [
+      Key::new(0., 0., mode),
+      Key::new(1., 5., mode),
+      Key::new(3., 2., mode),
+      Key::new(4., 10., mode),
+    ];
+    let spline = from_keys::<f64, f64, 4>(&keys);
+
+    for i in 1..20 {
+      let t = 1. + i as f64 / 10.;
+      let dt = 1e-6;
+      let derivative = |t| spline.sample_with_derivative(t).unwrap().1;
+      let expected = (derivative(t + dt) - derivative(t - dt)) / (2. * dt);
+      let curvature = spline.curvature(t).unwrap();
+
+      assert!(
+        approx_eq!(f64, curvature, expected, epsilon = 1e-4),
+        "{:?} at {}: {} != {}",
+        mode,
+        t,
+        curvature,
+        expected
+      );
+    }
+  }
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 5., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline.curvature(0.7), Some(0.));
+  assert_eq!(spline.curvature(1.5), None);
+}
+
+#[test]
+fn derivative_spline() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Linear),
+    Key::new(3., 1., Interpolation::Step(0.5)),
+    Key::new(4., 5., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+  let derivative = spline.derivative_spline::<8>();
+
+  assert_eq!(derivative.sample(0.5), Some(2.));
+  assert_eq!(derivative.sample(1.), Some(-0.5));
+  assert_eq!(derivative.sample(2.5), Some(-0.5));
+  assert_eq!(derivative.sample(3.5), Some(0.));
+  assert_eq!(derivative.sample(4.), Some(0.));
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::CatmullRom),
+    Key::new(2., 3., Interpolation::CatmullRom),
+    Key::new(3., 2., Interpolation::Cosine),
+    Key::new(
+      4.,
+      0.,
+      Interpolation::Hermite {
+        in_tangent: 1.,
+        out_tangent: 1.,
+      },
+    ),
+    Key::new(6., 1., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 6>(&keys);
+  let derivative = spline.derivative_spline::<12>();
+  let eps = 1e-6;
+
+  for i in 0..48 {
+    let t = f64::from(i) / 8. + 1. / 16.;
+
+    // skip the cosine segment, which is only approximated
+    if (3. ..4.).contains(&t) {
+      continue;
+    }
+
+    let expected = (spline.sample(t + eps).unwrap() - spline.sample(t - eps).unwrap()) / (2. * eps);
+    assert!(
+      (derivative.sample(t).unwrap() - expected).abs() < 1e-6,
+      "t = {}",
+      t
+    );
+  }
+
+  assert!(
+    (derivative.sample(3.5).unwrap() - spline.sample_with_derivative(3.5).unwrap().1).abs() < 0.2
+  );
+}
+
+#[test]
+fn arc_length() {
+  use float_cmp::approx_eq;
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Step(0.5)),
+    Key::new(2., 20., Interpolation::Cosine),
+    Key::new(3., 25., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  // the step segment doesn’t contribute
+  assert!(approx_eq!(f64, spline.arc_length(10), 15., epsilon = 1e-9));
+
+  let empty = Spline::<f64, f64, 4>::from_vec(Vec::new());
+  assert_eq!(empty.arc_length(10), 0.);
+}
+
+#[test]
+fn reparameterize_by_arc_length() {
+  use float_cmp::approx_eq;
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 9., Interpolation::Linear),
+    Key::new(5., 10., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 11>(&keys);
+  let reparameterized = spline.reparameterize_by_arc_length::<11>(11, 16).unwrap();
+
+  assert_eq!(reparameterized.len(), 11);
+
+  for (i, key) in reparameterized.keys().iter().enumerate() {
+    assert!(approx_eq!(f64, key.t, i as f64, epsilon = 1e-9));
+    assert!(approx_eq!(f64, key.value, i as f64, epsilon = 1e-9));
+  }
+
+  assert!(approx_eq!(
+    f64,
+    reparameterized.sample(2.5).unwrap(),
+    2.5,
+    epsilon = 1e-9
+  ));
+
+  // degenerate cases: zero length and no contributing segment
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 11>(&keys);
+
+  assert_eq!(
+    spline
+      .reparameterize_by_arc_length::<5>(5, 16)
+      .unwrap()
+      .keys(),
+    &[Key::new(0., 0., Interpolation::Linear)]
+  );
+
+  let keys = [
+    Key::new(0., 3., Interpolation::Step(0.5)),
+    Key::new(1., 3., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 11>(&keys);
+
+  assert!(spline
+    .reparameterize_by_arc_length::<5>(5, 16)
+    .unwrap()
+    .is_empty());
+
+  // the new spline must hold the keys
+  assert!(spline.reparameterize_by_arc_length::<4>(5, 16).is_none());
+}
+
+#[test]
+fn length_table() {
+  // the spline speeds up then slows down, reaching the middle in a tenth of the time
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(10., 20., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+  let table = spline.build_length_table::<129>(64).unwrap();
+
+  assert!((table.length() - 20.).abs() < 1e-9);
+  assert_eq!(table.parameter_at_distance(-1.), 0.);
+  assert_eq!(table.parameter_at_distance(25.), 10.);
+  assert!((table.parameter_at_distance(5.) - 0.5).abs() < 1e-9);
+
+  // equal distances yield equally spaced values
+  let values: std::vec::Vec<_> = (0..=20)
+    .map(|i| {
+      let t = table.parameter_at_distance(i as f64);
+      spline.sample(t).unwrap()
+    })
+    .collect();
+
+  for pair in values.windows(2) {
+    assert!((pair[1] - pair[0] - 1.).abs() < 0.01, "spacing: {:?}", pair);
+  }
+
+  assert!(Spline::<f64, f64, 3>::default()
+    .build_length_table::<8>(4)
+    .is_none());
+}
+
+#[test]
+fn nearest() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Cosine),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 30., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  let (t, value) = spline.nearest(&20., 4).unwrap();
+  assert!((t - 1.5).abs() < 1e-6, "{}", t);
+  assert!((value - 20.).abs() < 1e-6, "{}", value);
+
+  let (t, value) = spline.nearest(&5., 4).unwrap();
+  assert!((t - 0.5).abs() < 1e-9, "{}", t);
+  assert!((value - 5.).abs() < 1e-9, "{}", value);
+
+  // targets out of the range of the spline snap to its extrema
+  assert_eq!(spline.nearest(&-5., 4), Some((0., 0.)));
+  assert_eq!(spline.nearest(&40., 4), Some((2., 30.)));
+  assert!(Spline::<f64, f64, 2>::from_vec(Vec::new())
+    .nearest(&1., 4)
+    .is_none());
+}
+
+#[test]
+fn bounding_box() {
+  let keys = [
+    Key::new(0., 10., Interpolation::CatmullRom),
+    Key::new(1., 0., Interpolation::CatmullRom),
+    Key::new(2., 0., Interpolation::CatmullRom),
+    Key::new(3., 10., Interpolation::CatmullRom),
+    Key::new(4., 10., Interpolation::Linear),
+    Key::new(5., 5., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 6>(&keys);
+  let (min, max) = spline.bounding_box(32).unwrap();
+
+  // Catmull-Rom overshoots its keys
+  assert!(min < 0.);
+  assert!(max > 10.);
+
+  for i in 10..50 {
+    let value = spline.sample(i as f64 / 10.).unwrap();
+    assert!(min <= value && value <= max);
+  }
+
+  let keys = [
+    Key::new(0., 2., Interpolation::Step(0.5)),
+    Key::new(1., -1., Interpolation::Linear),
+    Key::new(2., 3., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.bounding_box(1), Some((-1., 3.)));
+  assert_eq!(from_keys::<f64, f64, 1>(&keys[..1]).bounding_box(1), None);
+}
+
+#[test]
+fn extrema() {
+  // symmetric bump peaking in the middle of its single segment
+  let keys = [
+    Key::new(
+      0.,
+      0.,
+      Interpolation::Hermite {
+        in_tangent: 1.,
+        out_tangent: 1.,
+      },
+    ),
+    Key::new(
+      2.,
+      0.,
+      Interpolation::Hermite {
+        in_tangent: -1.,
+        out_tangent: -1.,
+      },
+    ),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+  let extrema = spline.extrema::<4>();
+
+  assert_eq!(extrema.len(), 1);
+  assert!((extrema[0].0 - 1.).abs() < 1e-12);
+  assert!((extrema[0].1 - 0.5).abs() < 1e-12);
+
+  // peak at a key, in between two linear segments, and trough inside a quintic segment
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::QuinticHermite),
+    Key::new(3., 0., Interpolation::QuinticHermite),
+    Key::new(4., 2., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+  let extrema = spline.extrema::<4>();
+
+  assert_eq!(extrema.len(), 2);
+  assert_eq!(extrema[0], (1., 2.));
+  assert!(extrema[1].0 > 2. && extrema[1].0 < 3.);
+  assert!(spline.sample_with_derivative(extrema[1].0).unwrap().1.abs() < 1e-9);
+
+  // monotone splines have no extrema
+  let keys = [
+    Key::new(0., 0., Interpolation::Cosine),
+    Key::new(1., 1., Interpolation::Linear),
+    Key::new(2., 3., Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 3>(&keys);
+
+  assert!(spline.extrema::<4>().is_empty());
+}
+
+#[test]
+fn value_range() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 0., Interpolation::CatmullRom),
+    Key::new(2., 10., Interpolation::CatmullRom),
+    Key::new(3., 10., Interpolation::CatmullRom),
+    Key::new(4., 0., Interpolation::CatmullRom),
+    Key::new(5., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 6>(&keys);
+
+  // the curve overshoots its keys in between the two highest ones
+  let (min, max) = spline.value_range(1).unwrap();
+  assert_eq!(min, 0.);
+  assert!(max > 10.);
+
+  let sampled = (0..=3000)
+    .filter_map(|i| spline.sample(1. + i as f64 / 1000.))
+    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+      (min.min(v), max.max(v))
+    });
+  assert!((min - sampled.0).abs() < 1e-6);
+  assert!((max - sampled.1).abs() < 1e-6);
+
+  assert_eq!(Spline::<f64, f64, 6>::default().value_range(8), None);
+}
+
+#[test]
+fn default_is_empty() {
+  let spline = Spline::<f64, f64, 4>::default();
+
+  assert!(spline.is_empty());
+  assert_eq!(spline.sample(0.), None);
+  assert_eq!(spline.clamped_sample(1.), None);
+}
+
+#[test]
+fn from_array() {
+  let spline = Spline::from_array([
+    Key::new(1., 10., Interpolation::default()),
+    Key::new(0., 0., Interpolation::Linear),
+  ]);
+
+  assert_eq!(spline.keys()[0].t, 0.);
+  assert_eq!(spline.sample(0.5), Some(5.));
+}
+
+#[test]
+fn from_keys_unsorted() {
+  let keys = [
+    Key::new(3., 30., Interpolation::Linear),
+    Key::new(f64::NAN, 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::Linear),
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 25., Interpolation::Linear),
+  ];
+  let spline = Spline::<f64, f64, 6>::from_keys_unsorted(Vec::from_slice(&keys).unwrap());
+  let keys: std::vec::Vec<_> = spline.into_iter().map(|key| (key.t, key.value)).collect();
+
+  assert_eq!(
+    keys[..5],
+    [(0., 0.), (1., 10.), (2., 20.), (2., 25.), (3., 30.)]
+  );
+  assert!(keys[5].0.is_nan());
+}
+
+#[test]
+fn try_from_keys() {
+  use splines::spline::BuildError;
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(1., 20., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = Spline::<f64, f64, 4>::try_from(Vec::from_slice(&keys).unwrap()).unwrap();
+  assert_eq!(spline.keys(), &keys);
+
+  let unsorted = [keys[0], keys[3], keys[1]];
+  assert_eq!(
+    Spline::<f64, f64, 4>::try_from(Vec::from_slice(&unsorted).unwrap()),
+    Err(BuildError::Unsorted { key: 2 })
+  );
+
+  let nan = [keys[0], Key::new(f64::NAN, 5., Interpolation::Linear)];
+  assert_eq!(
+    Spline::<f64, f64, 4>::try_from(Vec::from_slice(&nan).unwrap()),
+    Err(BuildError::NotComparable { key: 1 })
+  );
+}
+
+#[test]
+fn from_values() {
+  let spline = Spline::<f64, f64, 3>::from_values([0., 10., 20.], Interpolation::Linear);
+
+  assert_eq!(spline.domain(), Some((0., 2.)));
+  assert_eq!(spline.sample(1.), Some(10.));
+  assert_eq!(spline.sample(1.5), Some(15.));
+  assert!(spline
+    .keys()
+    .iter()
+    .all(|key| key.interpolation == Interpolation::Linear));
+}
+
+#[test]
+fn collect() {
+  let keys = [
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(0., 10., Interpolation::Linear),
+    Key::new(3., 20., Interpolation::default()),
+    Key::new(1., 5., Interpolation::Linear),
+  ];
+  let spline: Spline<f64, f64, 4> = keys.iter().copied().collect();
+
+  assert_eq!(
+    spline
+      .keys()
+      .iter()
+      .map(|key| key.t)
+      .collect::<std::vec::Vec<_>>(),
+    [0., 1., 2., 3.]
+  );
+  assert_eq!(spline.sample(0.5), Some(7.5));
+  assert_eq!(spline.sample(2.5), Some(10.));
+}
+
+#[test]
+#[should_panic(expected = "spline capacity (2) exceeded")]
+fn collect_too_many_keys() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 0., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::Linear),
+  ];
+  let _: Spline<f64, f64, 2> = keys.into_iter().collect();
+}
+
+#[test]
+fn extend() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::Linear),
+  ];
+  let mut spline = from_keys::<f64, f64, 5>(&keys);
+
+  spline.extend([
+    Key::new(3., 30., Interpolation::default()),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(-1., -10., Interpolation::Linear),
+  ]);
+
+  assert_eq!(
+    spline
+      .keys()
+      .iter()
+      .map(|key| key.t)
+      .collect::<std::vec::Vec<_>>(),
+    [-1., 0., 1., 2., 3.]
+  );
+
+  for i in -10..30 {
+    let t = i as f64 / 10.;
+    assert!((spline.sample(t).unwrap() - t * 10.).abs() < 1e-9);
+  }
+}
+
+#[test]
+#[should_panic(expected = "spline capacity (2) exceeded")]
+fn extend_too_many_keys() {
+  let mut spline = Spline::<f64, f64, 2>::from_vec(Vec::new());
+  spline.extend([
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 0., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::Linear),
+  ]);
+}
+
+#[test]
+fn builder() {
+  let spline = Spline::<f64, f64, 3>::builder()
+    .step(2., 20., 0.5)
+    .linear(0., 0.)
+    .bezier(1., 10., 15.)
+    .build()
+    .unwrap();
+  let interpolations: std::vec::Vec<_> =
+    spline.keys().iter().map(|key| key.interpolation).collect();
+
+  assert_eq!(
+    interpolations,
+    [
+      Interpolation::Linear,
+      Interpolation::Bezier(15.),
+      Interpolation::Step(0.5)
+    ]
+  );
+  assert_eq!(spline.sample(0.5), Some(5.));
+  assert_eq!(spline.sample(1.), Some(10.));
+}
+
+#[test]
+fn builder_not_enough_keys() {
+  use splines::spline::BuildError;
+
+  let result = Spline::<f64, f64, 2>::builder().linear(0., 0.).build();
+  assert_eq!(result, Err(BuildError::NotEnoughKeys { len: 1 }));
+}
+
+#[test]
+fn key_tags() {
+  let keys = [
+    Key::with_tag(0., 0., Interpolation::Linear, "start"),
+    Key::with_tag(1., 10., Interpolation::Linear, "middle"),
+    Key::with_tag(2., 20., Interpolation::default(), "end"),
+  ];
+  let mut spline = Spline::<f64, f64, 3, &str>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  let sampled = spline.sample_with_key(1.5).unwrap();
+  assert_eq!(sampled.value, 15.);
+  assert_eq!(spline.keys()[sampled.key].tag, "middle");
+
+  // tags don’t take part in interpolation and follow their keys
+  *spline.get_mut(0).unwrap().tag = "first";
+  let reversed = spline.reverse();
+  assert_eq!(reversed.keys()[2].tag, "first");
+  assert_eq!(reversed.sample(0.5), Some(15.));
+}
+
+#[test]
+fn spline_eq() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Bezier(1.)),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+  let same = from_keys::<f64, f64, 3>(&keys);
+  assert_eq!(spline, same);
+
+  let mut different = same.clone();
+  different.replace(0, |key| {
+    Key::new(key.t, key.value, Interpolation::Bezier(2.))
+  });
+  assert_ne!(spline, different);
+}
+
+#[test]
+fn hash() {
+  use std::collections::hash_map::DefaultHasher;
+  use std::hash::{Hash, Hasher};
+
+  let hash = |spline: &Spline<i32, i64, 2>| {
+    let mut hasher = DefaultHasher::new();
+    spline.hash(&mut hasher);
+    hasher.finish()
+  };
+  let keys = [
+    Key::new(0, 10, Interpolation::Step(1)),
+    Key::new(4, -2, Interpolation::Bezier(3)),
+  ];
+  let a = from_keys(&keys);
+  let b = from_keys(&[keys[1], keys[0]]);
+
+  assert_eq!(a, b);
+  assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn clone_non_copy_values() {
+  let keys = [
+    Key::new(0., "a".to_owned(), Interpolation::Bezier("u".to_owned())),
+    Key::new(1., "b".to_owned(), Interpolation::Bezier("v".to_owned())),
+  ];
+  let spline = from_keys::<f32, String, 2>(&keys);
+  let cloned = spline.clone();
+
+  assert_eq!(cloned, spline);
+  assert_eq!(
+    cloned.keys()[1].interpolation,
+    Interpolation::Bezier("v".to_owned())
+  );
+}
+
+#[test]
+fn domain() {
+  let keys = [
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(-1., 5., Interpolation::Linear),
+    Key::new(6., 2., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.domain(), Some((-1., 6.)));
+  assert_eq!(
+    from_keys::<f64, f64, 1>(&keys[..1]).domain(),
+    Some((2., 2.))
+  );
+  assert_eq!(Spline::<f64, f64, 1>::from_vec(Vec::new()).domain(), None);
+}
+
+#[test]
+fn accessors() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(2., 20., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.len(), 3);
+  assert!(!spline.is_empty());
+  assert_eq!(spline.get(1), Some(&keys[1]));
+  assert_eq!(spline.get(3), None);
+}
+
+#[test]
+fn capacity() {
+  let mut spline = Spline::<f32, f32, 1000>::default();
+  let storage = spline.keys().as_ptr();
+
+  for i in 0..1000 {
+    spline.add(Key::new(i as f32, 0., Interpolation::Linear));
+    assert_eq!(spline.capacity(), 1000);
+  }
+
+  assert_eq!(spline.len(), 1000);
+  assert_eq!(spline.keys().as_ptr(), storage);
+}
+
+#[test]
+fn keys_slice() {
+  let keys = [
+    Key::new(1., 10., Interpolation::default()),
+    Key::new(0., 0., Interpolation::Linear),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline.keys().len(), 2);
+  assert_eq!(spline.keys().first(), Some(&keys[1]));
+}
+
+#[test]
+fn index() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+
+  assert_eq!(spline[0], keys[0]);
+  assert_eq!(spline[1], keys[1]);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+  let spline = Spline::<f64, f64, 2>::from_vec(Vec::new());
+  let _ = spline[0];
+}
+
+#[test]
+fn first_and_last() {
+  let start = Key::new(0., 0., Interpolation::Linear);
+  let end = Key::new(1., 10., Interpolation::default());
+  let mut spline = from_keys::<f32, f32, 2>(&[end, start]);
+
+  assert_eq!(spline.first(), Some(&start));
+  assert_eq!(spline.last(), Some(&end));
+
+  spline.clear();
+  assert_eq!(spline.first(), None);
+  assert_eq!(spline.last(), None);
+}
+
+#[test]
+fn contains() {
+  let keys = [
+    Key::new(-1., 0., Interpolation::Linear),
+    Key::new(2., 10., Interpolation::default()),
+  ];
+  let mut spline = from_keys::<f32, f32, 2>(&keys);
+
+  assert!(spline.contains(-1.));
+  assert!(spline.contains(0.5));
+  assert!(spline.contains(2.));
+  assert!(!spline.contains(-1.1));
+  assert!(!spline.contains(2.1));
+
+  spline.clear();
+  assert!(!spline.contains(0.));
+}
+
+#[test]
+fn owned_iterator() {
+  let keys = [
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(-1., 5., Interpolation::Cosine),
+    Key::new(6., 2., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+  let expected: std::vec::Vec<_> = spline.keys().to_vec();
+
+  let owned: std::vec::Vec<_> = spline.into_iter().collect();
+  assert_eq!(owned, expected);
+  assert_eq!(owned[0].t, -1.);
+}
+
+#[test]
+fn segments() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::Cosine),
+    Key::new(2., 2., Interpolation::Step(0.5)),
+    Key::new(3., 1., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+  let segments: std::vec::Vec<_> = spline.segments().collect();
+
+  assert_eq!(segments.len(), 3);
+  assert_eq!(segments[0], (&keys[0], &keys[1]));
+  assert_eq!(segments[2], (&keys[2], &keys[3]));
+
+  let single = from_keys::<f64, f64, 1>(&keys[..1]);
+  assert_eq!(single.segments().count(), 0);
+}
+
+#[test]
+fn segments_with_mode() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Bezier(1.)),
+    Key::new(1., 3., Interpolation::Linear),
+    Key::new(2., 1., Interpolation::Bezier(2.)),
+    Key::new(3., 4., Interpolation::Step(0.5)),
+    Key::new(4., 2., Interpolation::Bezier(0.)),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+
+  let modes: std::vec::Vec<_> = spline.segments_with_mode().collect();
+  assert_eq!(modes.len(), 4);
+  assert_eq!(modes[1], (&keys[1], &keys[2], &Interpolation::Linear));
+
+  // the last key doesn’t start a segment, so its mode isn’t counted
+  let bezier = spline
+    .segments_with_mode()
+    .filter(|(_, _, interpolation)| matches!(interpolation, Interpolation::Bezier(_)))
+    .count();
+  assert_eq!(bezier, 2);
+}
+
+#[test]
+fn windows() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 20., Interpolation::CatmullRom),
+    Key::new(3., 30., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  let windows: std::vec::Vec<_> = spline.windows(3).collect();
+  assert_eq!(windows, [&keys[0..3], &keys[1..4]]);
+
+  assert_eq!(spline.windows(4).count(), 1);
+  assert_eq!(spline.windows(5).count(), 0);
+}
+
+#[test]
+fn reversed_iterator() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::Cosine),
+    Key::new(2., 2., Interpolation::Linear),
+    Key::new(3., 7., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  let reversed: std::vec::Vec<_> = (&spline).into_iter().rev().collect();
+  let expected: std::vec::Vec<_> = spline.keys().iter().rev().collect();
+  assert_eq!(reversed, expected);
+
+  // both ends can be consumed without overlapping
+  let mut iter = (&spline).into_iter();
+  assert_eq!(iter.next(), Some(&keys[0]));
+  assert_eq!(iter.next_back(), Some(&keys[3]));
+  assert_eq!(iter.next_back(), Some(&keys[2]));
+  assert_eq!(iter.next(), Some(&keys[1]));
+  assert_eq!(iter.next(), None);
+  assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn exact_size_iterators() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::Linear),
+    Key::new(2., 7., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  let mut iter = (&spline).into_iter();
+  assert_eq!(iter.len(), 3);
+  assert_eq!(iter.size_hint(), (3, Some(3)));
+  iter.next();
+  iter.next_back();
+  assert_eq!(iter.size_hint(), (1, Some(1)));
+
+  let mut owned = spline.into_iter();
+  assert_eq!(owned.len(), 3);
+  owned.next();
+  assert_eq!(owned.size_hint(), (2, Some(2)));
+  owned.by_ref().for_each(drop);
+  assert_eq!(owned.len(), 0);
+}
+
+#[test]
+fn insert() {
+  let mut spline = Spline::<f64, f64, 5>::from_vec(Vec::new());
+
+  for (t, value) in [(2., 20.), (0., 0.), (3., 30.), (1., 10.), (2., 25.)] {
+    spline.insert(Key::new(t, value, Interpolation::Linear));
+  }
+
+  let keys: std::vec::Vec<_> = spline.into_iter().map(|key| (key.t, key.value)).collect();
+  assert_eq!(keys, [(0., 0.), (1., 10.), (2., 20.), (2., 25.), (3., 30.)]);
+}
+
+#[test]
+fn add_full() {
+  let mut spline = Spline::<f64, f64, 1>::from_vec(Vec::new());
+  spline.add(Key::new(1., 10., Interpolation::Linear));
+
+  // the key is dropped
+  spline.add(Key::new(0., 0., Interpolation::Linear));
+  assert_eq!(spline.keys(), &[Key::new(1., 10., Interpolation::Linear)]);
+
+  let key = Key::new(0., 0., Interpolation::Linear);
+  assert_eq!(spline.try_add(key), Err(key));
+  assert_eq!(spline.len(), 1);
+
+  let mut spline = Spline::<f64, f64, 2>::from_vec(Vec::new());
+  spline.add(Key::new(1., 10., Interpolation::Linear));
+  assert_eq!(spline.try_add(key), Ok(()));
+  assert_eq!(spline.keys()[0], key);
+}
+
+#[test]
+#[should_panic(expected = "spline capacity (1) exceeded")]
+fn insert_full() {
+  let mut spline = Spline::<f64, f64, 1>::from_vec(Vec::new());
+  spline.insert(Key::new(0., 0., Interpolation::Linear));
+  spline.insert(Key::new(1., 0., Interpolation::Linear));
+}
+
+#[test]
+fn push() {
+  let mut spline = Spline::<f32, f32, 3>::default();
+
+  spline.push(Key::new(0., 0., Interpolation::Linear));
+  spline.push(Key::new(1., 10., Interpolation::Linear));
+  spline.push(Key::new(1., 20., Interpolation::default()));
+
+  assert_eq!(spline.len(), 3);
+  assert_eq!(spline.sample(0.5), Some(5.));
+  assert_eq!(spline.sample(1.), Some(20.));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "keys must be pushed in order")]
+fn push_out_of_order() {
+  let mut spline = Spline::<f32, f32, 2>::default();
+
+  spline.push(Key::new(1., 10., Interpolation::Linear));
+  spline.push(Key::new(0., 0., Interpolation::default()));
+}
+
+#[test]
+fn remove() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let mut spline = from_keys::<f64, f64, 3>(&keys);
+
+  assert_eq!(spline.remove(3), None);
+  assert_eq!(spline.remove(1), Some(keys[1]));
+  assert_eq!(spline.keys(), &[keys[0], keys[2]]);
+  assert_eq!(spline.sample(1.), Some(0.));
+
+  assert_eq!(spline.remove(0), Some(keys[0]));
+  assert_eq!(spline.sample(2.), None);
+  assert_eq!(spline.clamped_sample(2.), Some(0.));
+}
+
+#[test]
+fn replace() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let mut spline = from_keys::<f64, f64, 3>(&keys);
+
+  let new_key = Key::new(1., 20., Interpolation::Linear);
+  assert_eq!(spline.replace(1, |_| new_key), Some(keys[1]));
+  assert_eq!(spline.sample(0.5), Some(10.));
+
+  // moving a key past its neighbors keeps the keys sorted
+  let moved = spline.replace(0, |key| Key::new(3., key.value, key.interpolation));
+  assert_eq!(moved, Some(keys[0]));
+  assert_eq!(
+    spline
+      .keys()
+      .iter()
+      .map(|key| key.t)
+      .collect::<std::vec::Vec<_>>(),
+    [1., 2., 3.]
+  );
+
+  assert_eq!(spline.replace(3, |_| new_key), None);
+}
+
+#[test]
+fn retain() {
+  let mut spline = Spline::<f64, f64, 5>::from_iter(
+    (0..5).map(|i| Key::new(i as f64, (i * i) as f64, Interpolation::Linear)),
+  );
+
+  let mut index = 0;
+  spline.retain(|_| {
+    index += 1;
+    index % 2 == 1
+  });
+
+  assert_eq!(spline.len(), 3);
+  assert_eq!(
+    spline
+      .keys()
+      .iter()
+      .map(|key| key.t)
+      .collect::<std::vec::Vec<_>>(),
+    [0., 2., 4.]
+  );
+  assert_eq!(spline.sample(1.), Some(2.));
+  assert_eq!(spline.sample(3.), Some(10.));
+
+  spline.retain(|key| key.value > 10.);
+  assert_eq!(spline.len(), 1);
+  assert_eq!(spline.sample(4.), None);
+}
+
+#[test]
+fn clear_and_truncate() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let mut spline = from_keys::<f32, f32, 3>(&keys);
+
+  spline.truncate(5);
+  assert_eq!(spline.len(), 3);
+
+  spline.truncate(2);
+  assert_eq!(spline.keys(), &keys[..2]);
+  assert_eq!(spline.sample(1.5), None);
+
+  spline.clear();
+  assert!(spline.is_empty());
+  assert_eq!(spline.sample(0.5), None);
+
+  spline.add(Key::new(0., 4., Interpolation::Linear));
+  spline.add(Key::new(2., 8., Interpolation::default()));
+  assert_eq!(spline.sample(1.), Some(6.));
+}
+
+#[test]
+fn values_mut() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::Cosine),
+    Key::new(2., 2., Interpolation::default()),
+  ];
+  let mut spline = from_keys::<f64, f64, 3>(&keys);
+
+  for value in spline.values_mut() {
+    *value = *value * 2. + 1.;
+  }
+
+  assert_eq!(spline.sample(0.), Some(1.));
+  assert_eq!(spline.sample(0.5), Some(6.));
+  assert_eq!(spline.sample(1.), Some(11.));
+  assert_eq!(spline.clamped_sample(2.), Some(5.));
+}
+
+#[test]
+fn map_values() {
+  let keys = [
+    Key::new(0., 1., Interpolation::Linear),
+    Key::new(1., 3., Interpolation::Cosine),
+    Key::new(2., -2., Interpolation::Bezier(5.)),
+    Key::new(3., 4., Interpolation::StrokeBezier(0.5, 2.)),
+    Key::new(4., 1., Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 5>(&keys);
+  let doubled = spline.map_values(|v| v * 2.);
+
+  assert_eq!(
+    doubled.keys()[3],
+    Key::new(3., 8., Interpolation::StrokeBezier(1., 4.))
+  );
+
+  for i in 0..=40 {
+    let t = i as f32 * 0.1;
+    let (a, b) = (
+      spline.clamped_sample(t).unwrap(),
+      doubled.clamped_sample(t).unwrap(),
+    );
+    assert!((a * 2. - b).abs() < 1e-5, "t = {}: {} != {}", t, a * 2., b);
+  }
+
+  // the value type can change
+  let wide: Spline<f32, f64, 5> = spline.map_values(|&v| v as f64);
+  assert_eq!(wide.sample(1.), Some(3.));
+}
+
+#[test]
+fn map_keys() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+
+  // mirror and stretch the time axis, offsetting values by time
+  let mapped =
+    spline.map_keys(|key| Key::new(-2. * key.t, key.value + key.t, Interpolation::Linear));
+
+  assert_eq!(
+    mapped
+      .keys()
+      .iter()
+      .map(|key| key.t)
+      .collect::<std::vec::Vec<_>>(),
+    [-4., -2., 0.]
+  );
+  assert_eq!(mapped.sample(-3.), Some(6.5));
+  assert_eq!(mapped.sample(-1.), Some(5.5));
+  assert_eq!(mapped.sample(0.), Some(0.));
+
+  let converted: Spline<f32, f32, 3> =
+    mapped.map_keys(|key| Key::new(key.t as f32, key.value as f32, Interpolation::Cosine));
+  assert_eq!(converted.sample(-4.), Some(2.));
+}
+
+#[test]
+fn split_at() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 5., Interpolation::Step(0.5)),
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(4., 10., Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 4>(&keys);
+
+  let (left, right) = spline.split_at(3.).unwrap();
+  assert_eq!(
+    left.keys(),
+    &[
+      keys[0],
+      keys[1],
+      keys[2],
+      Key::new(3., 5., Interpolation::Linear)
+    ]
+  );
+  assert_eq!(
+    right.keys(),
+    &[Key::new(3., 5., Interpolation::Linear), keys[3]]
+  );
+
+  for t in [0., 0.5, 1., 1.25, 1.75, 2., 2.5] {
+    assert_eq!(left.sample(t), spline.sample(t));
+  }
+
+  for t in [3., 3.5] {
+    assert_eq!(right.sample(t), spline.sample(t));
+  }
+
+  assert_eq!(left.clamped_sample(3.), right.sample(3.));
+
+  // splitting on a key doesn’t duplicate it
+  let (left, right) = spline.split_at(1.).unwrap();
+  assert_eq!(left.keys(), &keys[..2]);
+  assert_eq!(right.keys(), &keys[1..]);
+
+  assert!(spline.split_at(-1.).is_none());
+  assert!(spline.split_at(5.).is_none());
+}
+
+#[test]
+fn concat() {
+  let up = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::default()),
+  ];
+  let down = [
+    Key::new(0., 10., Interpolation::Linear),
+    Key::new(1., 0., Interpolation::default()),
+  ];
+  let up = from_keys::<f32, f32, 2>(&up);
+  let down = from_keys::<f32, f32, 2>(&down);
+  let spline: Spline<_, _, 4> = up.concat(&down, 1.);
+
+  assert_eq!(
+    spline.keys(),
+    &[
+      Key::new(0., 0., Interpolation::Linear),
+      Key::new(1., 10., Interpolation::Linear),
+      Key::new(2., 0., Interpolation::default()),
+    ]
+  );
+  assert_eq!(spline.sample(0.5), Some(5.));
+  assert_eq!(spline.sample(1.), Some(10.));
+  assert_eq!(spline.sample(1.5), Some(5.));
+  assert!((spline.sample(0.999).unwrap() - spline.sample(1.001).unwrap()).abs() < 1e-1);
+}
+
+#[test]
+fn reverse() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Bezier(4.)),
+    Key::new(1., 10., Interpolation::StrokeBezier(12., 6.)),
+    Key::new(2., 5., Interpolation::Step(0.25)),
+    Key::new(3., 8., Interpolation::Cosine),
+    Key::new(4., 2., Interpolation::Bezier(-1.)),
+    Key::new(5., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 6>(&keys);
+  let reversed = spline.reverse();
+
+  assert_eq!(reversed.len(), spline.len());
+  assert_eq!(reversed.keys()[0].t, 0.);
+  assert_eq!(reversed.keys()[0].value, 4.);
+
+  for i in 0..=50 {
+    let t = i as f64 * 0.1;
+
+    match (spline.clamped_sample(t), reversed.clamped_sample(5. - t)) {
+      (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9, "t = {}: {} != {}", t, a, b),
+      other => panic!("t = {}: {:?}", t, other),
+    }
+  }
+
+  // reversing twice gives back the same keys, though Bézier tangents might be expressed differently
+  let twice = reversed.reverse();
+  for (a, b) in spline.keys().iter().zip(twice.keys()) {
+    assert_eq!((a.t, a.value), (b.t, b.value));
+  }
+
+  assert!(Spline::<f64, f64, 2>::from_vec(Vec::new())
+    .reverse()
+    .is_empty());
+}
+
+#[test]
+fn shift() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(0.5, 10., Interpolation::Cosine),
+    Key::new(1., 5., Interpolation::Smoothstep),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+  let mut shifted = spline.clone();
+  shifted.shift(2.);
+
+  assert_eq!(shifted.domain(), Some((2., 4.)));
+
+  for i in 0..=16 {
+    let t = i as f64 / 8.;
+    assert_eq!(shifted.sample(t + 2.), spline.sample(t));
+  }
+}
+
+#[test]
+fn scale_parameter() {
+  let keys = [
+    Key::new(1., 0., Interpolation::Linear),
+    Key::new(2., 10., Interpolation::Cosine),
+    Key::new(3., 5., Interpolation::Smoothstep),
+    Key::new(5., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+  let mut scaled = spline.clone();
+  scaled.scale_parameter(0.5);
+
+  assert_eq!(scaled.domain(), Some((0.5, 2.5)));
+
+  for i in 8..=40 {
+    let t = i as f64 / 8.;
+    assert_eq!(scaled.sample(t * 0.5), spline.sample(t));
+  }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn scale_parameter_negative() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::default()),
+  ];
+  let mut spline = from_keys::<f32, f32, 2>(&keys);
+  spline.scale_parameter(-1.);
+}
+
+#[test]
+fn remap_parameter() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(0.5, 10., Interpolation::Step(0.5)),
+    Key::new(0.75, 5., Interpolation::Cosine),
+    Key::new(1., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 4>(&keys);
+  let remapped = spline.remap_parameter(|t| t * t);
+
+  assert_eq!(
+    remapped.keys(),
+    &[
+      Key::new(0., 0., Interpolation::Linear),
+      Key::new(0.25, 10., Interpolation::Step(0.5)),
+      Key::new(0.5625, 5., Interpolation::Cosine),
+      Key::new(1., 0., Interpolation::default()),
+    ]
+  );
+  assert_eq!(remapped.sample(0.125), Some(5.));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn remap_parameter_not_monotone() {
+  let keys = [
+    Key::new(-1., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::default()),
+  ];
+  let spline = from_keys::<f32, f32, 2>(&keys);
+  spline.remap_parameter(|t| t * t);
+}
+
+#[test]
+fn clamp_domain() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 10., Interpolation::Cosine),
+    Key::new(3., 0., Interpolation::Linear),
+    Key::new(4., 5., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 4>(&keys);
+
+  let clamped = spline.clamp_domain(0.5, 3.5).unwrap();
+  assert_eq!(clamped.domain(), Some((0.5, 3.5)));
+  assert_eq!(clamped.len(), 4);
+  assert_eq!(clamped.sample(0.5), spline.sample(0.5));
+  assert_eq!(clamped.sample(3.5), spline.sample(3.5));
+  assert_eq!(clamped.sample(0.4), None);
+
+  // interpolation modes inside the range are preserved
+  let modes: std::vec::Vec<_> = clamped.keys().iter().map(|key| key.interpolation).collect();
+  assert_eq!(
+    &modes[..3],
+    [
+      Interpolation::Linear,
+      Interpolation::Cosine,
+      Interpolation::Linear
+    ]
+  );
+  assert_eq!(clamped.sample(2.), spline.sample(2.));
+
+  // existing keys are kept at the bounds
+  assert_eq!(spline.clamp_domain(1., 3.).unwrap().keys(), &keys[1..3]);
+  assert_eq!(spline.clamp_domain(2., 2.).unwrap().len(), 1);
+
+  assert!(spline.clamp_domain(-1., 2.).is_none());
+  assert!(spline.clamp_domain(2., 1.).is_none());
+}
+
+#[test]
+fn invert() {
+  let keys = [
+    Key::new(0., 1., Interpolation::Linear),
+    Key::new(1., 3., Interpolation::Linear),
+    Key::new(3., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+  let inverse = spline.invert().unwrap();
+
+  for i in 0..=24 {
+    let t = f64::from(i) / 8.;
+    let value = spline.sample(t).unwrap();
+    assert!((inverse.sample(value).unwrap() - t).abs() < 1e-12);
+  }
+
+  // decreasing splines are inverted too
+  let keys = [
+    Key::new(0., 4., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+  let inverse = spline.invert().unwrap();
+
+  assert_eq!(inverse.keys()[0].t, 0.);
+  assert_eq!(inverse.sample(1.), Some(1.5));
+
+  // non-monotone and flat splines cannot be inverted
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 2., Interpolation::Linear),
+    Key::new(2., 1., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 3>(&keys);
+  assert_eq!(spline.invert(), None);
+
+  let keys = [
+    Key::new(0., 0., Interpolation::Step(0.5)),
+    Key::new(1., 2., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 2>(&keys);
+  assert_eq!(spline.invert(), None);
+}
+
+#[test]
+fn simplify() {
+  // noisy straight line
+  let spline = Spline::<f64, f64, 101>::from_iter((0..=100).map(|i| {
+    let t = i as f64 / 10.;
+    let noise = if i % 2 == 0 { 0.01 } else { -0.01 };
+    Key::new(
+      t,
+      2. * t + if i % 100 == 0 { 0. } else { noise },
+      Interpolation::Linear,
+    )
+  }));
+  let simplified = spline.simplify(0.1);
+
+  assert_eq!(simplified.keys(), &[spline.keys()[0], spline.keys()[100]]);
+
+  // keys of other modes and corners are kept
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Linear),
+    Key::new(2., 2., Interpolation::Linear),
+    Key::new(3., 0., Interpolation::Linear),
+    Key::new(4., -2., Interpolation::Cosine),
+    Key::new(5., 0., Interpolation::Linear),
+    Key::new(6., 2., Interpolation::Linear),
+    Key::new(7., 4., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 8>(&keys);
+  let simplified = spline.simplify(0.1);
+
+  assert_eq!(
+    simplified.keys(),
+    &[keys[0], keys[2], keys[4], keys[5], keys[7]]
+  );
+}
+
+#[test]
+fn densify() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 5., Interpolation::CatmullRom),
+    Key::new(3., 20., Interpolation::Step(0.5)),
+    Key::new(4., 15., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 5>(&keys);
+  let dense = spline.densify::<32>(9);
+
+  // the first segment cannot be sampled and the step segment is not subdivided
+  assert_eq!(dense.len(), 23);
+  assert_eq!(dense.keys()[0], Key::new(0., 0., Interpolation::Linear));
+  assert_eq!(dense.keys()[1], Key::new(1., 10., Interpolation::Linear));
+  assert_eq!(dense.keys()[11], Key::new(2., 5., Interpolation::Linear));
+  assert_eq!(dense.keys()[21], keys[3]);
+  assert_eq!(dense.keys()[22].value, 15.);
+
+  for i in 0..=100 {
+    let t = 1. + i as f64 / 100.;
+    let error = (dense.sample(t).unwrap() - spline.sample(t).unwrap()).abs();
+    assert!(error < 0.1, "error at {}: {}", t, error);
+  }
+
+  assert_eq!(dense.sample(3.25), Some(20.));
+  assert_eq!(dense.sample(3.75), Some(15.));
+}
+
+#[test]
+fn resample() {
+  let keys = [
+    Key::new(0., 0., Interpolation::CatmullRom),
+    Key::new(1., 10., Interpolation::CatmullRom),
+    Key::new(2., 5., Interpolation::CatmullRom),
+    Key::new(3., 20., Interpolation::CatmullRom),
+    Key::new(4., 15., Interpolation::CatmullRom),
+    Key::new(5., 30., Interpolation::default()),
+  ];
+  let spline = from_keys::<f64, f64, 6>(&keys);
+  let resampled = spline.resample::<101>(101);
+
+  // parameters on the first and last segments cannot be sampled: only the endpoints are kept there
+  assert_eq!(resampled.len(), 62);
+  assert_eq!(resampled.keys()[0], Key::new(0., 0., Interpolation::Linear));
+  assert_eq!(
+    resampled.keys()[61],
+    Key::new(5., 30., Interpolation::Linear)
+  );
+  assert!(resampled
+    .keys()
+    .iter()
+    .all(|key| key.interpolation == Interpolation::Linear));
+
+  for i in 0..60 {
+    let t = 1. + 3. * i as f64 / 60.;
+    let error = (resampled.sample(t).unwrap() - spline.sample(t).unwrap()).abs();
+    assert!(error < 0.1, "error at {}: {}", t, error);
+  }
+}
+
+#[test]
+fn blend() {
+  let a = from_keys::<f64, f64, 2>(&[
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(2., 20., Interpolation::default()),
+  ]);
+  let b = from_keys::<f64, f64, 3>(&[
+    Key::new(1., 10., Interpolation::Linear),
+    Key::new(2., 0., Interpolation::Linear),
+    Key::new(3., 10., Interpolation::default()),
+  ]);
+
+  let blended = a.blend::<3, 8>(&b, 0.5);
+  let ts: std::vec::Vec<_> = blended.keys().iter().map(|key| key.t).collect();
+  assert_eq!(ts, [0., 1., 2., 3.]);
+
+  // b is clamped to its first value before 1, and a to its last value after 2
+  assert_eq!(blended.sample(0.), Some(5.));
+  assert_eq!(blended.sample(1.), Some(10.));
+  assert_eq!(blended.sample(2.), Some(10.));
+  assert_eq!(blended.sample(3.), Some(15.));
+
+  assert_eq!(a.blend::<3, 8>(&b, 0.).sample(1.), a.sample(1.));
+  assert!(a.blend::<0, 8>(&Spline::default(), 0.5).is_empty());
+}
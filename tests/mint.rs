@@ -0,0 +1,63 @@
+#![cfg(feature = "mint")]
+
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn mint_vector_interpolation() {
+  let keys = [
+    Key::new(
+      0.,
+      mint::Vector3::from([0., 2., -4.]),
+      Interpolation::Linear,
+    ),
+    Key::new(1., mint::Vector3::from([1., 4., 4.]), Interpolation::Cosine),
+    Key::new(
+      2.,
+      mint::Vector3::from([3., 4., 0.]),
+      Interpolation::default(),
+    ),
+  ];
+  let spline = Spline::<f32, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(mint::Vector3::from([0.5, 3., 0.])));
+  assert_eq!(spline.sample(1.), Some(mint::Vector3::from([1., 4., 4.])));
+  assert_eq!(
+    spline.clamped_sample(2.),
+    Some(mint::Vector3::from([3., 4., 0.]))
+  );
+}
+
+#[test]
+fn mint_quaternion_slerp() {
+  use float_cmp::approx_eq;
+
+  // rotations of 0 and 90° around z
+  let half = std::f64::consts::FRAC_1_SQRT_2;
+  let keys = [
+    Key::new(
+      0.,
+      mint::Quaternion::from([0., 0., 0., 1.]),
+      Interpolation::Linear,
+    ),
+    Key::new(
+      1.,
+      mint::Quaternion::from([0., 0., half, half]),
+      Interpolation::default(),
+    ),
+  ];
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  // halfway is a rotation of 45° around z, which naive linear interpolation doesn’t yield
+  let q: [f64; 4] = spline.sample(0.5).unwrap().into();
+  let angle = std::f64::consts::FRAC_PI_8;
+
+  assert!(approx_eq!(f64, q[2], angle.sin(), epsilon = 1e-12));
+  assert!(approx_eq!(f64, q[3], angle.cos(), epsilon = 1e-12));
+
+  let q: [f64; 4] = spline.sample(0.25).unwrap().into();
+  let angle = std::f64::consts::FRAC_PI_8 / 2.;
+
+  assert!(approx_eq!(f64, q[2], angle.sin(), epsilon = 1e-12));
+  assert!(approx_eq!(f64, q[3], angle.cos(), epsilon = 1e-12));
+}
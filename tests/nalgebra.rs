@@ -1,6 +1,8 @@
 #![cfg(feature = "nalgebra")]
 
+use heapless::Vec;
 use nalgebra as na;
+use splines::{Interpolation, Key, Spline};
 
 #[test]
 fn nalgebra_vector_interpolation() {
@@ -14,3 +16,61 @@ fn nalgebra_vector_interpolation() {
   assert_eq!(Interpolate::lerp(1., start, end), end);
   assert_eq!(Interpolate::lerp(0.5, start, end), mid);
 }
+
+#[test]
+fn arc_length_straight_diagonal() {
+  let keys = [
+    Key::new(0., na::Vector3::new(0., 0., 0.), Interpolation::Cosine),
+    Key::new(1., na::Vector3::new(1., 2., 2.), Interpolation::Linear),
+    Key::new(3., na::Vector3::new(2., 4., 4.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert!((spline.arc_length(16) - 6.).abs() < 1e-9);
+}
+
+#[test]
+fn quaternion_norm() {
+  use splines::interpolate::Norm;
+
+  assert_eq!(na::Quaternion::new(1., 2., 2., 4.).norm(), 5.);
+}
+
+#[test]
+fn bounding_box_componentwise() {
+  let keys = [
+    Key::new(0., na::Vector2::new(0., 3.), Interpolation::Linear),
+    Key::new(1., na::Vector2::new(2., -1.), Interpolation::Linear),
+    Key::new(2., na::Vector2::new(1., 1.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(
+    spline.bounding_box(1),
+    Some((na::Vector2::new(0., -1.), na::Vector2::new(2., 3.)))
+  );
+}
+
+#[test]
+fn circular_arc_quarter_circle() {
+  let point = |deg: f64| {
+    let rad = deg.to_radians();
+    na::Vector3::new(2. * rad.cos() + 1., 2. * rad.sin() - 1., 0.5)
+  };
+  let keys = [
+    Key::new(0., point(0.), Interpolation::CircularArc),
+    Key::new(1., point(45.), Interpolation::CircularArc),
+    Key::new(2., point(90.), Interpolation::default()),
+  ];
+  let spline = Spline::<f64, _, 3>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  for (t, deg) in [(0.5, 22.5), (1.25, 56.25), (1.5, 67.5)] {
+    let p = spline.sample(t).unwrap();
+    assert!((p - point(deg)).norm() < 1e-9);
+  }
+
+  // the velocity is tangent to the circle, with a constant speed of 45° per unit of parameter
+  let (p, v) = spline.sample_with_derivative(1.5).unwrap();
+  assert!(v.dot(&(p - na::Vector3::new(1., -1., 0.5))).abs() < 1e-9);
+  assert!((v.norm() - 2. * 45f64.to_radians()).abs() < 1e-9);
+}
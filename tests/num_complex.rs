@@ -0,0 +1,29 @@
+#![cfg(feature = "num-complex")]
+
+use heapless::Vec;
+use num_complex::Complex;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn complex_lerp() {
+  let start = Key::new(0., Complex::new(1., 0.), Interpolation::Linear);
+  let end = Key::new(1., Complex::new(0., 1.), Interpolation::default());
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(Complex::new(0.5, 0.5)));
+}
+
+#[test]
+fn complex_catmull_rom() {
+  let keys = [
+    Key::new(0., Complex::new(0., 0.), Interpolation::CatmullRom),
+    Key::new(1., Complex::new(1., 2.), Interpolation::CatmullRom),
+    Key::new(2., Complex::new(2., 4.), Interpolation::CatmullRom),
+    Key::new(3., Complex::new(3., 6.), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 4>::from_vec(Vec::from_slice(&keys).unwrap());
+  let z = spline.sample(1.5).unwrap();
+
+  assert!((z.re - 1.5).abs() < 1e-6);
+  assert!((z.im - 3.).abs() < 1e-6);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "palette")]
+
+use heapless::Vec;
+use palette::{Lcha, Oklab, Oklch};
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn oklch_hue_shortest_arc() {
+  let start = Key::new(0., Oklch::new(0.5, 0.1, 350.), Interpolation::Linear);
+  let end = Key::new(1., Oklch::new(0.5, 0.1, 10.), Interpolation::default());
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
+
+  let hue = spline.sample(0.5).unwrap().hue.into_positive_degrees();
+  assert!(
+    hue < 1e-6 || (360. - hue) < 1e-6,
+    "hue went the long way: {}",
+    hue
+  );
+
+  let hue = spline.sample(0.25).unwrap().hue.into_positive_degrees();
+  assert!((hue - 355.).abs() < 1e-6);
+}
+
+#[test]
+fn lcha_hue_shortest_arc() {
+  let start = Key::new(0., Lcha::new(50., 30., 10., 1.), Interpolation::Linear);
+  let end = Key::new(1., Lcha::new(50., 30., 350., 0.), Interpolation::default());
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
+  let color: Lcha = spline.sample(0.75).unwrap();
+
+  assert!((color.hue.into_positive_degrees() - 355.).abs() < 1e-3);
+  assert!((color.alpha - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn oklab_lerp() {
+  let start = Key::new(0., Oklab::new(0.2, -0.1, 0.1), Interpolation::Linear);
+  let end = Key::new(1., Oklab::new(0.6, 0.1, -0.1), Interpolation::default());
+  let spline = Spline::<f64, _, 2>::from_vec(Vec::from_slice(&[start, end]).unwrap());
+  let color = spline.sample(0.5).unwrap();
+
+  assert!((color.l - 0.4).abs() < 1e-9);
+  assert!(color.a.abs() < 1e-9);
+  assert!(color.b.abs() < 1e-9);
+}
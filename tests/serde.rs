@@ -0,0 +1,90 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use splines::compact::CompactSpline;
+use splines::{Interpolation, Key, Spline};
+
+#[test]
+fn deserialize_unsorted_keys() {
+  let value = json!([
+    { "t": 5., "interpolation": "linear", "value": 10. },
+    { "t": 0., "interpolation": "linear", "value": 0. },
+    { "t": 1., "interpolation": "cosine", "value": 1. },
+    { "t": 1., "interpolation": "linear", "value": 2. },
+  ]);
+  let spline = serde_json::from_value::<Spline<f32, f32, 4>>(value).unwrap();
+  let keys: Vec<_> = spline
+    .keys()
+    .iter()
+    .map(|key| (key.t, key.value, key.interpolation))
+    .collect();
+
+  assert_eq!(
+    keys,
+    [
+      (0., 0., Interpolation::Linear),
+      (1., 1., Interpolation::Cosine),
+      (1., 2., Interpolation::Linear),
+      (5., 10., Interpolation::Linear),
+    ]
+  );
+}
+
+#[test]
+fn serialize_round_trip() {
+  let value = json!([
+    { "t": 0., "interpolation": "linear", "value": 0. },
+    { "t": 1., "interpolation": { "step": 0.5 }, "value": 1. },
+    {
+      "t": 2.,
+      "interpolation": { "hermite": { "in_tangent": -1., "out_tangent": 2. } },
+      "value": 3.,
+    },
+  ]);
+  let spline = serde_json::from_value::<Spline<f32, f32, 3>>(value.clone()).unwrap();
+
+  assert_eq!(serde_json::to_value(&spline).unwrap(), value);
+}
+
+#[test]
+fn compact_round_trip() {
+  let keys = [
+    Key::new(0., 0., Interpolation::Linear),
+    Key::new(1., 1., Interpolation::Step(0.5)),
+    Key::new(5., 10., Interpolation::Cosine),
+    Key::new(6., 12., Interpolation::default()),
+  ];
+  let spline = Spline::<f64, f64, 4>::from_vec(heapless::Vec::from_slice(&keys).unwrap());
+  let value = serde_json::to_value(CompactSpline::from(spline.clone())).unwrap();
+
+  assert_eq!(
+    value,
+    json!({
+      "t": [0., 1., 5., 6.],
+      "values": [0., 1., 10., 12.],
+      "interpolations": ["linear", { "step": 0.5 }, "cosine", "linear"],
+    })
+  );
+
+  let round_trip: Spline<f64, f64, 4> = serde_json::from_value::<CompactSpline<f64, f64, 4>>(value)
+    .unwrap()
+    .into();
+
+  assert_eq!(round_trip.keys(), spline.keys());
+
+  for t in [0., 0.5, 1.5, 3., 5.5] {
+    assert_eq!(round_trip.sample(t), spline.sample(t));
+  }
+}
+
+#[test]
+fn compact_mismatched_lengths() {
+  let value = json!({
+    "t": [0., 1.],
+    "values": [0.],
+    "interpolations": ["linear", "linear"],
+  });
+  let err = serde_json::from_value::<CompactSpline<f64, f64, 2>>(value).unwrap_err();
+
+  assert!(err.to_string().contains("mismatched lengths"));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "ultraviolet")]
+
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+use ultraviolet::{Rotor3, Vec3};
+
+#[test]
+fn vec3_interpolation() {
+  let keys = [
+    Key::new(0., Vec3::new(0., 0., 0.), Interpolation::Linear),
+    Key::new(1., Vec3::new(2., 4., -2.), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(Vec3::new(1., 2., -1.)));
+  assert_eq!(spline.sample(1.), Some(Vec3::new(2., 4., -2.)));
+}
+
+#[test]
+fn rotor_slerp() {
+  let start = Rotor3::from_rotation_xz(0.);
+  let end = Rotor3::from_rotation_xz(170f32.to_radians());
+  let keys = [
+    Key::new(0., start, Interpolation::Linear),
+    Key::new(1., end, Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap();
+  let expected = Rotor3::from_rotation_xz(85f32.to_radians());
+
+  assert!((mid.mag() - 1.).abs() < 1e-6);
+  assert!((mid.s - expected.s).abs() < 1e-6);
+  assert!((mid.bv.xz - expected.bv.xz).abs() < 1e-6);
+}
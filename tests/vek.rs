@@ -0,0 +1,32 @@
+#![cfg(feature = "vek")]
+
+use heapless::Vec;
+use splines::{Interpolation, Key, Spline};
+use vek::{Quaternion, Vec2};
+
+#[test]
+fn vec2_interpolation() {
+  let keys = [
+    Key::new(0., Vec2::new(0f32, 0.), Interpolation::Linear),
+    Key::new(1., Vec2::new(2., 4.), Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+
+  assert_eq!(spline.sample(0.5), Some(Vec2::new(1., 2.)));
+}
+
+#[test]
+fn quaternion_slerp() {
+  let start = Quaternion::<f32>::rotation_y(0.);
+  let end = Quaternion::rotation_y(170f32.to_radians());
+  let keys = [
+    Key::new(0., start, Interpolation::Linear),
+    Key::new(1., end, Interpolation::default()),
+  ];
+  let spline = Spline::<f32, _, 2>::from_vec(Vec::from_slice(&keys).unwrap());
+  let mid = spline.sample(0.5).unwrap();
+  let expected = Quaternion::rotation_y(85f32.to_radians());
+
+  assert!((mid.magnitude() - 1.).abs() < 1e-6);
+  assert!((mid.into_vec4() - expected.into_vec4()).magnitude() < 1e-6);
+}